@@ -1,5 +1,5 @@
-use pinpayments::{Client, Charge, ChargeSearchParams, SortByField, SortDirection};
-//use time::macros::datetime;
+use pinpayments::{Client, Charge, ChargeSearchParams, ChargeSortByField, SortDirection};
+use time::macros::datetime;
 
 #[tokio::main]
 async fn main() { 
@@ -27,17 +27,15 @@ async fn main() {
 
     println!("Result is {charge:?}");
 
-//    let search_params = ChargeSearchParams {
-//        query: Some("Kruger"),
-//        start_date: Some(datetime!(2017-02-24 3:10:49 UTC)),
-//        end_date: None,
-//        sort_by: Some(SortByField::CreatedAt),
-//        direction: Some(SortDirection::Asc),
-//        page: None,
-//        per_page: None
-//    };
-//
-    let search_params = ChargeSearchParams { ..Default::default() };
+    let search_params = ChargeSearchParams {
+        query: Some("Kruger"),
+        start_date: Some(datetime!(2017-02-24 3:10:49 UTC)),
+        end_date: None,
+        sort_by: Some(ChargeSortByField::CreatedAt),
+        direction: Some(SortDirection::Asc),
+        page: None,
+        per_page: None
+    };
 
     let searched_charges = Charge::search(&client, search_params).await.unwrap();
 
@@ -1,9 +1,15 @@
 use std::future::{self};
+use std::time::Duration;
 use futures::future::BoxFuture;
 
-use http_types::{Request};
+use ::async_std::task::sleep;
+use http_client::isahc::IsahcClient;
+use http_types::{Request, StatusCode};
+use isahc::config::Configurable;
 use serde::de::DeserializeOwned;
 
+use crate::client::{RetryPolicy, Timeouts};
+use crate::client::timeout::with_timeout;
 use crate::error::{ErrorResponse, PinError};
 
 pub type Response<T> = BoxFuture<'static, Result<T, PinError>>;
@@ -24,11 +30,49 @@ pub(crate) fn err<T: Send + 'static>(err: PinError) -> Response<T> {
 #[derive(Clone, Debug)]
 pub struct BaseClient {
     client: surf::Client,
+    retry_policy: RetryPolicy,
+    timeouts: Timeouts,
+}
+
+impl Default for BaseClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BaseClient {
     pub fn new() -> Self {
-        Self { client: surf::Client::new() }
+        Self {
+            client: surf::Client::new(),
+            retry_policy: RetryPolicy::never(),
+            timeouts: Timeouts::default(),
+        }
+    }
+
+    /// Set the policy used to retry requests which fail transiently.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the limits after which requests are abandoned with `PinError::Timeout`.
+    ///
+    /// A `connect` limit is applied by the http client itself, so setting one replaces the
+    /// client and its connection pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `connect` limit is set and the underlying http client cannot be built.
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        if let Some(connect) = timeouts.connect {
+            let client = isahc::HttpClient::builder()
+                .connect_timeout(connect)
+                .build()
+                .expect("unable to build http client");
+            self.client = surf::Client::with_http_client(IsahcClient::from_client(client));
+        }
+        self.timeouts = timeouts;
+        self
     }
 
     pub fn execute<T: DeserializeOwned + Send + 'static>(
@@ -38,9 +82,11 @@ impl BaseClient {
         // As the client could be used across threads it is cloned.
         // The client is send sync and cloned clients share the same pool.
         let client = self.client.clone();
+        let retry_policy = self.retry_policy.clone();
+        let timeouts = self.timeouts;
 
         Box::pin(async move {
-            let bytes = send_inner(&client, request).await?;
+            let bytes = send_inner(&client, request, &retry_policy, timeouts).await?;
             let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
             serde_path_to_error::deserialize(json_deserializer).map_err(PinError::from)
         })
@@ -52,87 +98,102 @@ impl BaseClient {
     ) -> StatusOnlyResponse {
 
         let client = self.client.clone();
+        let retry_policy = self.retry_policy.clone();
+        let timeouts = self.timeouts;
 
         Box::pin(async move {
-            Ok(send_inner_status_only(&client, request).await?)
+            send_inner_status_only(&client, request, &retry_policy, timeouts).await
         })
     }
 }
 
 async fn send_inner(
     client: &surf::Client,
-    mut request: Request,
+    request: Request,
+    retry_policy: &RetryPolicy,
+    timeouts: Timeouts,
 ) -> Result<Vec<u8>, PinError> {
-
-    let body = request.body_bytes().await?;
-
-    // clone the request before send so it can
-    // be re-used if the need to retry arises.
-    let mut request = request.clone();
-    request.set_body(body.clone());
-
-    let mut response = match client.send(request).await {
-        Ok(response) => {
-            response
-        },
-        Err(err) => { 
-            return Err(PinError::from(err))
-        }
-    };
-
-    let status = response.status();
-
-    let bytes = response.body_bytes().await?;
-
-    if !status.is_success() {
-        let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-        let error = serde_path_to_error::deserialize(json_deserializer)
-            .map(|e: ErrorResponse| {
-                PinError::from(e)
-            })
-            .unwrap_or_else(PinError::from);
-
-            return Err(error)
-    }
-
+    let (_, bytes) = with_timeout(
+        timeouts.total,
+        send_with_retries(client, request, retry_policy, timeouts.per_attempt),
+    ).await?;
     Ok(bytes)
 }
 
 async fn send_inner_status_only(
     client: &surf::Client,
-    mut request: Request,
+    request: Request,
+    retry_policy: &RetryPolicy,
+    timeouts: Timeouts,
 ) -> Result<u16, PinError> {
+    let (status, _) = with_timeout(
+        timeouts.total,
+        send_with_retries(client, request, retry_policy, timeouts.per_attempt),
+    ).await?;
+    Ok(u16::from(status))
+}
 
-    let body = request.body_bytes().await?;
-
-    let mut request = request.clone();
-    request.set_body(body.clone());
+async fn send_with_retries(
+    client: &surf::Client,
+    mut request: Request,
+    retry_policy: &RetryPolicy,
+    attempt_timeout: Option<Duration>,
+) -> Result<(StatusCode, Vec<u8>), PinError> {
 
-    let mut response = match client.send(request).await {
-        Ok(response) => {
-            response
-        },
-        Err(err) => {
-            return Err(PinError::from(err))
+    let body = request.body_bytes().await?;
+    let retryable = RetryPolicy::is_idempotent(request.method());
+    let mut attempt = 1;
+
+    loop {
+        // clone the request before send so it can
+        // be re-used if the need to retry arises.
+        let mut attempt_request = request.clone();
+        attempt_request.set_body(body.clone());
+
+        let can_retry = retryable && retry_policy.should_retry(attempt);
+
+        let sent = with_timeout(attempt_timeout, async {
+            client.send(attempt_request).await.map_err(PinError::from)
+        }).await;
+
+        let mut response = match sent {
+            Ok(response) => {
+                response
+            },
+            Err(_) if can_retry => {
+                sleep(retry_policy.delay(attempt, None)).await;
+                attempt += 1;
+                continue;
+            },
+            Err(err) => {
+                return Err(err)
+            }
+        };
+
+        let status = response.status();
+
+        if can_retry && RetryPolicy::is_retryable_status(status) {
+            let retry_after = RetryPolicy::retry_after(&response);
+            sleep(retry_policy.delay(attempt, retry_after)).await;
+            attempt += 1;
+            continue;
         }
-    };
 
-    let status = response.status();
+        let bytes = response.body_bytes().await?;
 
-    let bytes = response.body_bytes().await?;
+        if !status.is_success() {
+            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+            let error = serde_path_to_error::deserialize(json_deserializer)
+                .map(|e: ErrorResponse| {
+                    PinError::from(e)
+                })
+                .unwrap_or_else(PinError::from);
 
-    if !status.is_success() {
-        let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-        let error = serde_path_to_error::deserialize(json_deserializer)
-            .map(|e: ErrorResponse| {
-                PinError::from(e)
-            })
-            .unwrap_or_else(PinError::from);
+                return Err(error)
+        }
 
-            return Err(error)
+        return Ok((status, bytes))
     }
-
-    Ok(u16::from(status))
 }
 
 
@@ -141,8 +202,16 @@ mod tests {
     use http_types::{Request, Url};
     use httpmock::prelude::*;
 
+    use std::time::Duration;
+
     use super::BaseClient;
-    use crate::{PinError};
+    use crate::{PinError, RetryPolicy, Timeouts};
+
+    fn fast_retry_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::exponential_backoff(max_attempts)
+            .with_base_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(5))
+    }
 
     #[async_std::test]
     async fn user_error() {
@@ -159,7 +228,7 @@ mod tests {
               ");
         });
 
-        let req = Request::get(Url::parse(&server.url("/1/missing")).unwrap());
+        let req = Request::get(Url::parse(&server.url("/1/missing")).expect("invalid url"));
         let res = client.execute::<()>(req).await;
 
         mock.assert_hits_async(1).await;
@@ -169,4 +238,201 @@ mod tests {
             _ => panic!("Expected PinPayments error {:?}", res),
         }
     }
+
+    #[async_std::test]
+    async fn retries_server_errors_for_idempotent_requests() {
+        let client = BaseClient::new().with_retry_policy(fast_retry_policy(3));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/1/flaky");
+            then.status(503).body("{
+                \"error\": \"service_unavailable\",
+                \"error_description\": \"The service is temporarily unavailable.\"
+              }
+              ");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/1/flaky")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(3).await;
+
+        match res {
+            Err(PinError::PinPayments(x)) => assert_eq!(x.error, "service_unavailable"),
+            _ => panic!("Expected PinPayments error {:?}", res),
+        }
+    }
+
+    #[async_std::test]
+    async fn honours_retry_after_on_too_many_requests() {
+        let client = BaseClient::new().with_retry_policy(fast_retry_policy(2));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/1/limited");
+            then.status(429).header("Retry-After", "0").body("{
+                \"error\": \"too_many_requests\",
+                \"error_description\": \"Slow down.\"
+              }
+              ");
+        });
+
+        let req = Request::delete(Url::parse(&server.url("/1/limited")).expect("invalid url"));
+        let res = client.execute_status_only(req).await;
+
+        mock.assert_hits_async(2).await;
+        assert!(res.is_err());
+    }
+
+    #[async_std::test]
+    async fn does_not_retry_non_idempotent_requests() {
+        let client = BaseClient::new().with_retry_policy(fast_retry_policy(3));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/1/flaky");
+            then.status(503).body("{}");
+        });
+
+        let req = Request::post(Url::parse(&server.url("/1/flaky")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_err());
+    }
+
+    #[async_std::test]
+    async fn does_not_retry_requests_with_idempotency_key() {
+        let client = BaseClient::new().with_retry_policy(fast_retry_policy(3));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/1/flaky").header("Idempotency-Key", "order-42");
+            then.status(503).body("{}");
+        });
+
+        let mut req = Request::post(Url::parse(&server.url("/1/flaky")).expect("invalid url"));
+        req.insert_header("Idempotency-Key", "order-42");
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_err());
+    }
+
+    #[async_std::test]
+    async fn does_not_retry_client_errors() {
+        let client = BaseClient::new().with_retry_policy(fast_retry_policy(3));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/1/missing");
+            then.status(404).body("{}");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/1/missing")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_err());
+    }
+
+    #[async_std::test]
+    async fn connect_timeout_client_sends_requests() {
+        let client = BaseClient::new()
+            .with_timeouts(Timeouts::default().with_connect(Duration::from_secs(5)));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/1/fast");
+            then.status(200).body("null");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/1/fast")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(1).await;
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn client_timeouts_are_reported_as_timeout() {
+        let err = http_types::Error::from(isahc::Error::Timeout);
+        assert!(matches!(PinError::from(err), PinError::Timeout));
+
+        let err = http_types::Error::from(isahc::Error::ConnectFailed);
+        assert!(matches!(PinError::from(err), PinError::ClientError(_)));
+    }
+
+    #[async_std::test]
+    async fn per_attempt_timeout() {
+        let client = BaseClient::new()
+            .with_timeouts(Timeouts::default().with_per_attempt(Duration::from_millis(50)));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/1/slow");
+            then.status(200).delay(Duration::from_millis(500)).body("null");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/1/slow")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(1).await;
+
+        match res {
+            Err(PinError::Timeout) => (),
+            _ => panic!("Expected Timeout error {:?}", res),
+        }
+    }
+
+    #[async_std::test]
+    async fn per_attempt_timeout_is_retried() {
+        let client = BaseClient::new()
+            .with_retry_policy(fast_retry_policy(2))
+            .with_timeouts(Timeouts::default().with_per_attempt(Duration::from_millis(50)));
+
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/1/slow");
+            then.status(200).delay(Duration::from_millis(500)).body("null");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/1/slow")).expect("invalid url"));
+        let res = client.execute::<()>(req).await;
+
+        mock.assert_hits_async(2).await;
+        assert!(matches!(res, Err(PinError::Timeout)));
+    }
+
+    #[async_std::test]
+    async fn total_timeout_spans_retries() {
+        let client = BaseClient::new()
+            .with_retry_policy(fast_retry_policy(10))
+            .with_timeouts(
+                Timeouts::default()
+                    .with_per_attempt(Duration::from_millis(50))
+                    .with_total(Duration::from_millis(120))
+            );
+
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(DELETE).path("/1/slow");
+            then.status(204).delay(Duration::from_millis(500));
+        });
+
+        let req = Request::delete(Url::parse(&server.url("/1/slow")).expect("invalid url"));
+        let res = client.execute_status_only(req).await;
+
+        assert!(matches!(res, Err(PinError::Timeout)));
+    }
 }
@@ -1,4 +1,7 @@
+mod multipart;
 mod pinpayments;
+mod retry;
+mod timeout;
 
 pub mod async_std;
 
@@ -13,3 +16,6 @@ pub use config::StatusOnlyResponse;
 
 pub use self::pinpayments::DEFAULT_TEST_API_BASE_URL;
 pub use self::pinpayments::Client;
+pub use self::multipart::Multipart;
+pub use self::retry::RetryPolicy;
+pub use self::timeout::Timeouts;
@@ -0,0 +1,99 @@
+use uuid::Uuid;
+
+/// A `multipart/form-data` request body.
+///
+/// The body is held in memory so that it can be replayed when a request is retried.
+#[derive(Clone, Debug)]
+pub struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Default for Multipart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Multipart {
+    pub fn new() -> Self {
+        Multipart {
+            boundary: format!("pinpayments-{}", Uuid::new_v4().simple()),
+            body: Vec::new(),
+        }
+    }
+
+    /// Add a text field.
+    pub fn text(mut self, name: &str, value: &str) -> Self {
+        self.part_header(name, None, None);
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Add a file field.
+    pub fn file(mut self, name: &str, filename: &str, mime_type: &str, contents: &[u8]) -> Self {
+        self.part_header(name, Some(filename), Some(mime_type));
+        self.body.extend_from_slice(contents);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    pub(crate) fn into_bytes(mut self) -> Vec<u8> {
+        self.body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    fn part_header(&mut self, name: &str, filename: Option<&str>, mime_type: Option<&str>) {
+        let mut header = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            escape(name)
+        );
+        if let Some(filename) = filename {
+            header.push_str(&format!("; filename=\"{}\"", escape(filename)));
+        }
+        header.push_str("\r\n");
+        if let Some(mime_type) = mime_type {
+            header.push_str(&format!("Content-Type: {}\r\n", mime_type));
+        }
+        header.push_str("\r\n");
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+/// Quotes and line breaks cannot appear in a quoted header parameter.
+fn escape(value: &str) -> String {
+    value.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Multipart;
+
+    #[test]
+    fn encodes_fields_and_files() {
+        let form = Multipart::new()
+            .text("purpose", "dispute_evidence")
+            .file("file", "receipt \"1\".pdf", "application/pdf", b"%PDF-1.4");
+        let boundary = form.boundary.clone();
+
+        assert_eq!(form.content_type(), format!("multipart/form-data; boundary={}", boundary));
+
+        let body = String::from_utf8(form.into_bytes()).expect("body is not utf-8");
+        assert_eq!(
+            body,
+            format!(
+                "--{b}\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\ndispute_evidence\r\n\
+                 --{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"receipt %221%22.pdf\"\r\n\
+                 Content-Type: application/pdf\r\n\r\n%PDF-1.4\r\n\
+                 --{b}--\r\n",
+                b = boundary
+            )
+        );
+    }
+}
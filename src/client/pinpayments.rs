@@ -2,12 +2,13 @@ use http_types::{Body, Method, Request, Url};
 use serde::{de::DeserializeOwned, Serialize};
 
 use surf::http::auth::BasicAuth;
+use uuid::Uuid;
 
 use crate::{
-    client::{BaseClient, Response, StatusOnlyResponse},
+    client::{BaseClient, Multipart, Response, RetryPolicy, StatusOnlyResponse, Timeouts},
     config::err,
     params::AppInfo,
-    Headers, PinError,
+    Headers, MerchantId, PinError,
 };
 
 const USER_AGENT: &str = concat!("PinPayments/1 RustClient/", env!("CARGO_PKG_VERSION"));
@@ -15,13 +16,16 @@ const USER_AGENT: &str = concat!("PinPayments/1 RustClient/", env!("CARGO_PKG_VE
 pub const DEFAULT_API_BASE_URL: &str = "https://api.pinpayments.com/1/";
 pub const DEFAULT_TEST_API_BASE_URL: &str = "https://test-api.pinpayments.com/1/";
 
+/// Header carrying the idempotency key sent with every `POST` and `PUT` request.
+pub(crate) const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
 #[derive(Clone, Debug)]
 pub struct Client {
     client: crate::client::BaseClient,
     secret_key: String,
     headers: Headers,
     app_info: Option<AppInfo>,
-    api_base: Url
+    api_base: Url,
 }
 
 impl Client {
@@ -32,12 +36,17 @@ impl Client {
 
     /// Create a new client making use of the specified URL. Typically used in sandbox and test
     /// scenarios.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `url` is not a valid URL.
     pub fn from_url<'a>(url: impl Into<&'a str>, secret_key: impl Into<String>) -> Self {
         Client {
             client: BaseClient::new(),
             secret_key: secret_key.into(),
             headers: Headers {
-                user_agent: USER_AGENT.to_string()
+                user_agent: USER_AGENT.to_string(),
+                merchant: None
             },
             app_info: None,
             api_base: Url::parse(url.into()).expect("invalid url"),
@@ -52,11 +61,34 @@ impl Client {
         url: Option<String>,
     ) -> Self {
         let app_info = AppInfo { name, version, url };
-        self.headers.user_agent = format!("{} {}", USER_AGENT, app_info.to_string());
+        self.headers.user_agent = format!("{} {}", USER_AGENT, app_info);
         self.app_info = Some(app_info);
         self
     }
 
+    /// Make requests on behalf of the presented merchant. Only available to partner accounts,
+    /// sent as the `X-Merchant-Token` header.
+    pub fn with_merchant(mut self, merchant: MerchantId) -> Self {
+        self.headers.merchant = Some(merchant.to_string());
+        self
+    }
+
+    /// Set the policy used to retry requests which fail transiently.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Set the limits after which requests are abandoned with `PinError::Timeout`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `connect` limit is set and the underlying http client cannot be built.
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.client = self.client.with_timeouts(timeouts);
+        self
+    }
+
     /// Make a http `GET` request using presented path
     pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
         let url = self.url(path);
@@ -119,14 +151,81 @@ impl Client {
     }
 
     /// Make a http `POST` request urlencoding the body
+    ///
+    /// # Panics
+    ///
+    /// Panics if the urlencoded form is not valid UTF-8.
     pub fn post_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
         &self,
         path: &str,
         form: F,
+    ) -> Response<T> {
+        self.send_form(Method::Post, path, form, None)
+    }
+
+    /// Make a http `POST` request urlencoding the body, sending `key` as the `Idempotency-Key`
+    /// header in place of a generated one.
+    ///
+    /// The key should identify a single logical operation so a caller can send it again when
+    /// replaying that operation. The Pin Payments API reference does not document
+    /// deduplication by this header, so the request is never retried automatically.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the urlencoded form is not valid UTF-8.
+    pub fn post_form_with_key<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+        key: &str,
+    ) -> Response<T> {
+        self.send_form(Method::Post, path, form, Some(key))
+    }
+
+    /// Make a http `PUT` request urlencoding the body
+    ///
+    /// # Panics
+    ///
+    /// Panics if the urlencoded form is not valid UTF-8.
+    pub fn put_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Response<T> {
+        self.send_form(Method::Put, path, form, None)
+    }
+
+    /// Make a http `POST` request with a `multipart/form-data` body
+    pub fn post_multipart<T: DeserializeOwned + Send + 'static>(
+        &self,
+        path: &str,
+        form: Multipart,
     ) -> Response<T> {
         let url = self.url(path);
         let mut req = self.create_request(Method::Post, url);
 
+        let content_type = form.content_type();
+        req.set_body(Body::from_bytes(form.into_bytes()));
+
+        req.insert_header("content-type", content_type);
+
+        self.client.execute::<T>(req)
+    }
+
+    fn send_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        form: F,
+        idempotency_key: Option<&str>,
+    ) -> Response<T> {
+        let url = self.url(path);
+        let mut req = self.create_request(method, url);
+
+        if let Some(key) = idempotency_key {
+            req.insert_header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+
         let mut params_buffer = Vec::new();
         let qs_ser = &mut serde_qs::Serializer::new(&mut params_buffer);
         if let Err(qs_ser_err) = serde_path_to_error::serialize(&form, qs_ser) {
@@ -145,8 +244,7 @@ impl Client {
 
     fn url(&self, path: &str) -> Url {
         let base = self.api_base.clone();
-        let url = base.join(path.trim_start_matches('/')).unwrap();
-        url
+        base.join(path.trim_start_matches('/')).expect("invalid path")
     }
 
     fn url_with_params<P: Serialize>(&self, path: &str, params: P) -> Result<Url, PinError> {
@@ -173,6 +271,10 @@ impl Client {
             req.insert_header(key, value);
         }
 
+        if matches!(method, Method::Post | Method::Put) {
+            req.insert_header(IDEMPOTENCY_KEY_HEADER, Uuid::new_v4().to_string());
+        }
+
         req
     }
 }
@@ -180,7 +282,9 @@ impl Client {
 
 #[cfg(test)]
 mod test {
-    use super::Client;
+    use http_types::Method;
+
+    use super::{Client, IDEMPOTENCY_KEY_HEADER};
 
     #[test]
     fn user_agent_base() {
@@ -219,4 +323,30 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn idempotency_key_generated_for_mutating_requests() {
+        let client = Client::new("sk_test_12345");
+
+        let post = client.create_request(Method::Post, client.url("/charges"));
+        let put = client.create_request(Method::Put, client.url("/charges/ch_123/capture"));
+        let get = client.create_request(Method::Get, client.url("/charges"));
+
+        let post_key = post.header(IDEMPOTENCY_KEY_HEADER).expect("missing idempotency key");
+        let put_key = put.header(IDEMPOTENCY_KEY_HEADER).expect("missing idempotency key");
+
+        assert_ne!(post_key.as_str(), put_key.as_str());
+        assert!(get.header(IDEMPOTENCY_KEY_HEADER).is_none());
+    }
+
+    #[test]
+    fn merchant_header() {
+        let client = Client::new("sk_test_12345");
+        let req = client.create_request(Method::Get, client.url("/charges"));
+        assert!(req.header("X-Merchant-Token").is_none());
+
+        let client = client.with_merchant("mrch_roZOG7c8IY4_8ak2gS8Yrw".parse().expect("invalid merchant"));
+        let req = client.create_request(Method::Get, client.url("/charges"));
+        assert_eq!(req.header("X-Merchant-Token").expect("missing merchant"), "mrch_roZOG7c8IY4_8ak2gS8Yrw");
+    }
 }
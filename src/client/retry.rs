@@ -0,0 +1,172 @@
+use std::time::{Duration, SystemTime};
+
+use http_types::{Method, StatusCode};
+use http_types::other::RetryAfter;
+
+/// Controls how a request is retried after a transient failure.
+///
+/// Connection failures, `429 Too Many Requests` and `5xx` responses are retried for
+/// idempotent methods (`GET`, `PUT`, `DELETE`, `HEAD` and `OPTIONS`). `POST` requests are
+/// never retried, even with an `Idempotency-Key`, as replaying one could create a second
+/// charge, refund or transfer. The delay between
+/// attempts grows exponentially from `base_delay` and is bounded by `max_delay`. A
+/// `Retry-After` header sent by Pin Payments takes precedence over the computed delay,
+/// though it is still bounded by `max_delay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The total number of attempts, including the first. A value of `1` disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub base_delay: Duration,
+    /// The upper bound on the delay between any two attempts.
+    pub max_delay: Duration,
+    /// Randomise each delay between half and all of its computed value.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// A policy which never retries. This is the default for a new client.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            jitter: false,
+        }
+    }
+
+    /// An exponential backoff policy making up to `max_attempts` attempts, starting with a
+    /// 500ms delay, doubling each time up to 30s, with jitter enabled.
+    pub fn exponential_backoff(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound on the delay between attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable jitter.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub(crate) fn is_idempotent(method: Method) -> bool {
+        matches!(method, Method::Get | Method::Put | Method::Delete | Method::Head | Method::Options)
+    }
+
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TooManyRequests || status.is_server_error()
+    }
+
+    /// Whether another attempt may follow the given (1 based) attempt.
+    pub(crate) fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// The delay to wait after the given (1 based) attempt has failed.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            delay
+        }
+    }
+
+    pub(crate) fn retry_after(headers: impl AsRef<http_types::Headers>) -> Option<Duration> {
+        let retry_after = RetryAfter::from_headers(headers).ok()??;
+        Some(retry_after.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::never()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http_types::{Method, Response, StatusCode};
+
+    use super::RetryPolicy;
+
+    #[test]
+    fn delay_doubles_up_to_max() {
+        let policy = RetryPolicy::exponential_backoff(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(1000))
+            .with_jitter(false);
+
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(4, None), Duration::from_millis(800));
+        assert_eq!(policy.delay(5, None), Duration::from_millis(1000));
+        assert_eq!(policy.delay(64, None), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy::exponential_backoff(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(10));
+
+        for _ in 0..100 {
+            let delay = policy.delay(3, None);
+            assert!(delay >= Duration::from_millis(200));
+            assert!(delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn retry_after_takes_precedence() {
+        let policy = RetryPolicy::exponential_backoff(3).with_max_delay(Duration::from_secs(5));
+
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(2))), Duration::from_secs(2));
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(60))), Duration::from_secs(5));
+
+        let mut response = Response::new(StatusCode::TooManyRequests);
+        response.insert_header("Retry-After", "3");
+        let retry_after = RetryPolicy::retry_after(&response).expect("missing retry after");
+        assert!(retry_after < Duration::from_secs(4));
+        assert!(retry_after > Duration::from_secs(2));
+    }
+
+    #[test]
+    fn retryable_requests() {
+        assert!(RetryPolicy::is_idempotent(Method::Get));
+        assert!(RetryPolicy::is_idempotent(Method::Delete));
+        assert!(!RetryPolicy::is_idempotent(Method::Post));
+
+        assert!(RetryPolicy::is_retryable_status(StatusCode::TooManyRequests));
+        assert!(RetryPolicy::is_retryable_status(StatusCode::ServiceUnavailable));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::NotFound));
+
+        assert!(!RetryPolicy::never().should_retry(1));
+        assert!(RetryPolicy::exponential_backoff(2).should_retry(1));
+        assert!(!RetryPolicy::exponential_backoff(2).should_retry(2));
+    }
+}
@@ -0,0 +1,51 @@
+use std::future::Future;
+use std::time::Duration;
+
+use ::async_std::future::timeout;
+
+use crate::error::PinError;
+
+/// Limits on how long a request may take before it is abandoned with `PinError::Timeout`.
+///
+/// The `connect` limit applies to establishing each connection to Pin Payments. The
+/// `per_attempt` limit applies to each attempt and covers establishing the connection and
+/// receiving the response headers. An attempt which exceeds either is retried according to the
+/// client's `RetryPolicy`. The `total` limit covers the whole call, including any retries and
+/// reading the response body.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    pub connect: Option<Duration>,
+    pub per_attempt: Option<Duration>,
+    pub total: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Set the limit on establishing a connection.
+    pub fn with_connect(mut self, connect: Duration) -> Self {
+        self.connect = Some(connect);
+        self
+    }
+
+    /// Set the limit on each attempt establishing a connection and receiving the response headers.
+    pub fn with_per_attempt(mut self, per_attempt: Duration) -> Self {
+        self.per_attempt = Some(per_attempt);
+        self
+    }
+
+    /// Set the limit on the whole call, including retries.
+    pub fn with_total(mut self, total: Duration) -> Self {
+        self.total = Some(total);
+        self
+    }
+}
+
+/// Run `future` to completion, failing with `PinError::Timeout` if it exceeds `limit`.
+pub(crate) async fn with_timeout<T, F>(limit: Option<Duration>, future: F) -> Result<T, PinError>
+where
+    F: Future<Output = Result<T, PinError>>,
+{
+    match limit {
+        Some(limit) => timeout(limit, future).await.map_err(|_| PinError::Timeout)?,
+        None => future.await,
+    }
+}
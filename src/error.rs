@@ -16,11 +16,19 @@ pub enum PinError {
     ClientError(String),
     #[error("timeout communicating with Pin Payments")]
     Timeout,
+    #[error("invalid webhook payload: {0}")]
+    InvalidWebhook(String),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
 }
 
 impl From<http_types::Error> for PinError {
     fn from(err: http_types::Error) -> PinError {
-        PinError::ClientError(err.to_string())
+        // the http client reports its own connect timeout as an error
+        match err.downcast_ref::<isahc::Error>() {
+            Some(isahc::Error::Timeout) => PinError::Timeout,
+            _ => PinError::ClientError(err.to_string()),
+        }
     }
 }
 
@@ -9,6 +9,7 @@ mod params;
 mod ids;
 mod error;
 mod resources;
+mod webhook_events;
 
 pub use crate::client::*;
 pub use crate::error::{ErrorCode, PinError};
@@ -18,6 +19,7 @@ pub use crate::params::{
     SortDirection
 };
 pub use crate::resources::*;
+pub use crate::webhook_events::*;
 
 
 use std::collections::HashMap;
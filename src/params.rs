@@ -17,13 +17,13 @@ pub struct AppInfo {
     pub version: Option<String>,
 }
 
-impl ToString for AppInfo {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for AppInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match (&self.version, &self.url) {
-            (Some(a), Some(b)) => format!("{}/{} ({})", &self.name, a, b),
-            (Some(a), None) => format!("{}/{}", &self.name, a),
-            (None, Some(b)) => format!("{} ({})", &self.name, b),
-            _ => self.name.to_string(),
+            (Some(a), Some(b)) => write!(f, "{}/{} ({})", &self.name, a, b),
+            (Some(a), None) => write!(f, "{}/{}", &self.name, a),
+            (None, Some(b)) => write!(f, "{} ({})", &self.name, b),
+            _ => write!(f, "{}", &self.name),
         }
     }
 }
@@ -31,12 +31,14 @@ impl ToString for AppInfo {
 #[derive(Clone, Debug)]
 pub struct Headers {
     pub user_agent: String,
+    pub merchant: Option<String>,
 }
 
 impl Headers {
-    pub fn to_array(&self) -> [(&str, Option<&str>); 1] {
+    pub fn to_array(&self) -> [(&str, Option<&str>); 2] {
         [
             ("User-Agent", Some(&self.user_agent)),
+            ("X-Merchant-Token", self.merchant.as_deref()),
         ]
     }
 }
@@ -90,6 +92,7 @@ pub struct Page<T> {
 
 pub type Metadata = HashMap<String, String>;
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub struct RangeBounds<T> {
@@ -1,3 +1,67 @@
+/// Defines a `#[non_exhaustive]` enum over the string values of an API field. Values this
+/// crate does not know about are kept in an `Other` variant, so new values introduced by
+/// Pin Payments do not break deserialization.
+macro_rules! def_string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $value:literal),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)*
+            /// A value not known to this crate.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(value) => value,
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Other(String::new())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(value.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                Ok($name::from(value.as_ref()))
+            }
+        }
+    };
+}
+
 mod currency;
 mod charge;
 mod card;
@@ -11,6 +75,15 @@ mod plan;
 mod subscription;
 mod dispute;
 mod file;
+mod webhook_endpoint;
+mod webhook;
+mod event;
+mod deposit;
+mod merchant;
+mod payment_source;
+mod apple_pay_domain;
+mod authorisation;
+mod money;
 
 pub use currency::*;
 pub use charge::*;
@@ -25,3 +98,12 @@ pub use plan::*;
 pub use subscription::*;
 pub use dispute::*;
 pub use file::*;
+pub use webhook_endpoint::*;
+pub use webhook::*;
+pub use event::*;
+pub use deposit::*;
+pub use merchant::*;
+pub use payment_source::*;
+pub use apple_pay_domain::*;
+pub use authorisation::*;
+pub use money::*;
@@ -0,0 +1,59 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response, StatusOnlyResponse};
+use crate::error::PinError;
+use crate::ids::{ApplePayId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::build_map;
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateApplePayDomain<'a> {
+    pub domain_name: &'a str
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ApplePayDomain {
+    pub token: ApplePayId,
+    pub domain_name: String,
+    pub verified: bool,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>
+}
+
+impl ApplePayDomain {
+    /// Register a domain with Apple Pay. The domain-association file must already be served
+    /// from `/.well-known/apple-developer-merchantid-domain-association` on that domain.
+    pub fn create(client: &Client, params: CreateApplePayDomain<'_>) -> Response<ApplePayDomain> {
+        unpack_contained(client.post_form("/apple_pay/domains", &params))
+    }
+
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<ApplePayDomain>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/apple_pay/domains", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<ApplePayDomain, PinError>> {
+        paginate(
+            move |page, per_page| {
+                ApplePayDomain::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn delete(client: &Client, token: &ApplePayId) -> StatusOnlyResponse {
+        client.delete_status_only(&format!("/apple_pay/domains/{}", token))
+    }
+
+    /// The contents of the domain-association file to serve before registering a domain.
+    pub fn domain_association_file(client: &Client) -> Response<String> {
+        unpack_contained(client.get("/apple_pay/domain_association"))
+    }
+}
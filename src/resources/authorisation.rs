@@ -0,0 +1,116 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response};
+use crate::config::err;
+use crate::error::PinError;
+use crate::ids::{AuthorisationId, PaymentSourceId};
+use crate::params::{Metadata, Page, Paginator, unpack_contained, paginate};
+use crate::resources::{
+    CardParams,
+    Card,
+    Charge,
+    Currency,
+    Money
+};
+use crate::build_map;
+
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateAuthorisation<'a> {
+    pub email: String,
+    pub description: &'a str,
+    pub amount: i64,
+    pub ip_address: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardParams<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_source_token: Option<PaymentSourceId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CaptureAuthorisation {
+    pub amount: i64
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Authorisation {
+    pub token: AuthorisationId,
+    pub success: bool,
+    /// The `amount` and `currency` of the authorisation.
+    #[serde(flatten)]
+    pub money: Money,
+    pub description: String,
+    pub email: String,
+    pub ip_address: String,
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>,
+    pub status_message: String,
+    pub error_message: Option<String>,
+    pub card: Card,
+    #[serde(default)]
+    pub captured_amount: i64,
+    pub expired: bool,
+    pub voided: bool,
+    pub metadata: Option<Metadata>,
+}
+
+impl Authorisation {
+    pub fn create(client: &Client, params: CreateAuthorisation<'_>) -> Response<Authorisation> {
+        unpack_contained(client.post_form("/authorisations", &params))
+    }
+
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Authorisation>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/authorisations", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Authorisation, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Authorisation::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &AuthorisationId) -> Response<Authorisation> {
+        unpack_contained(client.get(&format!("/authorisations/{}", token)))
+    }
+
+    pub fn void(client: &Client, token: &AuthorisationId) -> Response<Authorisation> {
+        unpack_contained(client.put(&format!("/authorisations/{}/void", token)))
+    }
+
+    /// Capture some or all of the authorised amount, creating a charge for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PinError::InvalidRequest`, without contacting Pin Payments, if `amount` is not
+    /// positive or exceeds the amount of the authorisation.
+    pub fn capture(client: &Client, authorisation: &Authorisation, amount: i64) -> Response<Charge> {
+        if amount <= 0 || amount > authorisation.money.amount {
+            return err(PinError::InvalidRequest(format!(
+                "capture amount {} must be between 1 and the authorisation amount {}",
+                amount, authorisation.money.amount
+            )));
+        }
+        let params = CaptureAuthorisation { amount };
+        unpack_contained(client.post_form(&format!("/authorisations/{}/charges", authorisation.token), &params))
+    }
+}
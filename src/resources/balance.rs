@@ -2,13 +2,9 @@ use serde::{Deserialize};
 
 use crate::client::{Client, Response};
 use crate::params::{unpack_contained};
-use crate::resources::{Currency};
+use crate::resources::{Money};
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct AmountCurrency {
-    pub amount: i64,
-    pub currency: Currency
-}
+pub type AmountCurrency = Money;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Balance {
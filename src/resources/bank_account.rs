@@ -23,6 +23,6 @@ pub struct BankAccount {
 
 impl BankAccount {
     pub fn create(client: &Client, params: CreateBankAccount<'_>) -> Response<BankAccount> {
-        unpack_contained(client.post_form(&format!("/bank_accounts"), &params))
+        unpack_contained(client.post_form("/bank_accounts", &params))
     }
 }
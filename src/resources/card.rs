@@ -4,6 +4,19 @@ use crate::params::{unpack_contained};
 
 use serde::{Deserialize, Serialize};
 
+def_string_enum! {
+    /// The card network of a card.
+    pub enum CardScheme {
+        Visa => "visa",
+        Master => "master",
+        AmericanExpress => "american_express",
+        Diners => "diners",
+        Discover => "discover",
+        Jcb => "jcb",
+        Eftpos => "eftpos",
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CardParams<'a> {
     pub number: &'a str,
@@ -22,7 +35,7 @@ pub struct CardParams<'a> {
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct Card {
     pub token: CardId,
-    pub scheme: String,
+    pub scheme: CardScheme,
     pub display_number: String,
     pub issuing_country: String,
     pub expiry_month: i64,
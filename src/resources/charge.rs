@@ -4,13 +4,15 @@ use http_types::Url;
 use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
+use crate::config::err;
 use crate::error::PinError;
-use crate::ids::{ChargeId, SessionId};
+use crate::ids::{ChargeId, PaymentSourceId, SessionId};
 use crate::params::{Metadata, Page, Paginator, unpack_contained, paginate, SortDirection};
 use crate::resources::{
     CardParams,
     Card,
-    Currency
+    Currency,
+    Money
 };
 use crate::build_map;
 
@@ -37,6 +39,9 @@ pub struct CreateCharge<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<CardParams<'a>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_source_token: Option<PaymentSourceId>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
 
@@ -60,8 +65,9 @@ pub struct CreateCharge<'a> {
 pub struct Charge {
     pub token: ChargeId,
     pub success: bool,
-    pub amount: i64,
-    pub currency: Currency,
+    /// The `amount` and `currency` of the charge.
+    #[serde(flatten)]
+    pub money: Money,
     pub description: String,
     pub email: String,
     pub ip_address: String,
@@ -85,6 +91,11 @@ pub struct Charge {
     pub metadata: Option<Metadata>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct CaptureCharge {
+    pub amount: i64
+}
+
 #[derive(Debug, Serialize)]
 pub struct VerifyCharge<'a> {
     pub session_token: &'a SessionId
@@ -92,7 +103,7 @@ pub struct VerifyCharge<'a> {
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
-pub enum SortByField {
+pub enum ChargeSortByField {
     CreatedAt,
     CapturedAt,
     Amount
@@ -107,7 +118,7 @@ pub struct ChargeSearchParams<'a> {
     #[serde(with = "time::serde::iso8601::option", skip_serializing_if = "Option::is_none")]
     pub end_date: Option<OffsetDateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<SortByField>,
+    pub sort_by: Option<ChargeSortByField>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub direction: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,9 +127,38 @@ pub struct ChargeSearchParams<'a> {
     pub per_page: Option<u32>
 }
 
+impl CreateCharge<'_> {
+    /// Check the charge locally for mistakes Pin Payments would otherwise reject.
+    ///
+    /// This is opt-in: `Charge::create` sends the charge as given and leaves validation to
+    /// Pin Payments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PinError::InvalidRequest` if the amount is not positive or Pin Payments does
+    /// not accept charges in the currency.
+    pub fn validate(&self) -> Result<(), PinError> {
+        if self.amount <= 0 {
+            return Err(PinError::InvalidRequest(format!("charge amount {} must be positive", self.amount)));
+        }
+        if let Some(currency) = self.currency {
+            if !currency.supports_charging() {
+                return Err(PinError::InvalidRequest(format!("charges in {:?} are not supported", currency)));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Charge {
     pub fn create(client: &Client, params: CreateCharge<'_>) -> Response<Charge> {
-        unpack_contained(client.post_form(&format!("/charges"), &params))
+        unpack_contained(client.post_form("/charges", &params))
+    }
+
+    /// Create a charge sending `key` as its idempotency key, so a replay of the same operation
+    /// carries the same key. See `Client::post_form_with_key`.
+    pub fn create_with_idempotency_key(client: &Client, params: CreateCharge<'_>, key: &str) -> Response<Charge> {
+        unpack_contained(client.post_form_with_key("/charges", &params, key))
     }
 
     pub fn void(client: &Client, token: &ChargeId) -> Response<Charge> {
@@ -129,6 +169,23 @@ impl Charge {
         unpack_contained(client.put(&format!("/charges/{}/capture", token)))
     }
 
+    /// Capture part of an authorised charge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PinError::InvalidRequest`, without contacting Pin Payments, if `amount` is not
+    /// positive or exceeds the amount of the charge.
+    pub fn capture_amount(client: &Client, charge: &Charge, amount: i64) -> Response<Charge> {
+        if amount <= 0 || amount > charge.money.amount {
+            return err(PinError::InvalidRequest(format!(
+                "capture amount {} must be between 1 and the charge amount {}",
+                amount, charge.money.amount
+            )));
+        }
+        let params = CaptureCharge { amount };
+        unpack_contained(client.put_form(&format!("/charges/{}/capture", charge.token), &params))
+    }
+
     pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Charge>> {
         let page = page.map(|s| s.to_string());
         let per_page = per_page.map(|s| s.to_string());
@@ -139,7 +196,7 @@ impl Charge {
         client.get_query("/charges", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Charge, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Charge, PinError>> {
         paginate(
             move |page, per_page| {
                 Charge::list(client, Some(page), Some(per_page))
@@ -157,22 +214,22 @@ impl Charge {
     }
 
     pub fn verify(client: &Client, session_token: &SessionId) -> Response<Charge> {
-        unpack_contained(client.get_query("/charges/verify", VerifyCharge { session_token: session_token }))
+        unpack_contained(client.get_query("/charges/verify", VerifyCharge { session_token }))
     }
 }
 
 impl fmt::Display for Charge {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.token, self.amount)
+        write!(f, "({}, {})", self.token, self.money.amount)
     }
 }
 
-impl fmt::Display for SortByField {
+impl fmt::Display for ChargeSortByField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SortByField::CreatedAt => write!(f, "created_at"),
-            SortByField::CapturedAt => write!(f, "captured_at"),
-            SortByField::Amount => write!(f, "amount")
+            ChargeSortByField::CreatedAt => write!(f, "created_at"),
+            ChargeSortByField::CapturedAt => write!(f, "captured_at"),
+            ChargeSortByField::Amount => write!(f, "amount")
         }
     }
 }
@@ -2,154 +2,221 @@ use serde::{Deserialize, Serialize};
 
 use crate::params::to_snakecase;
 
-/// Currency is the list of supported currencies.
-///
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-pub enum Currency {
-    AED, // United Arab Emirates Dirham
-    AFN, // Afghan Afghani
-    ALL, // Albanian Lek
-    AMD, // Armenian Dram
-    ANG, // Netherlands Antillean Gulden
-    AOA, // Angolan Kwanza
-    ARS, // Argentine Peso
-    AUD, // Australian Dollar
-    AWG, // Aruban Florin
-    AZN, // Azerbaijani Manat
-    BAM, // Bosnia & Herzegovina Convertible Mark
-    BBD, // Barbadian Dollar
-    BDT, // Bangladeshi Taka
-    BGN, // Bulgarian Lev
-    BIF, // Burundian Franc
-    BMD, // Bermudian Dollar
-    BND, // Brunei Dollar
-    BOB, // Bolivian Boliviano
-    BRL, // Brazilian Real
-    BSD, // Bahamian Dollar
-    BWP, // Botswana Pula
-    BZD, // Belize Dollar
-    CAD, // Canadian Dollar
-    CDF, // Congolese Franc
-    CHF, // Swiss Franc
-    CLP, // Chilean Peso
-    CNY, // Chinese Renminbi Yuan
-    COP, // Colombian Peso
-    CRC, // Costa Rican Colón
-    CVE, // Cape Verdean Escudo
-    CZK, // Czech Koruna
-    DJF, // Djiboutian Franc
-    DKK, // Danish Krone
-    DOP, // Dominican Peso
-    DZD, // Algerian Dinar
-    EEK, // Estonian Kroon
-    EGP, // Egyptian Pound
-    ETB, // Ethiopian Birr
-    EUR, // Euro
-    FJD, // Fijian Dollar
-    FKP, // Falkland Islands Pound
-    GBP, // British Pound
-    GEL, // Georgian Lari
-    GIP, // Gibraltar Pound
-    GMD, // Gambian Dalasi
-    GNF, // Guinean Franc
-    GTQ, // Guatemalan Quetzal
-    GYD, // Guyanese Dollar
-    HKD, // Hong Kong Dollar
-    HNL, // Honduran Lempira
-    HRK, // Croatian Kuna
-    HTG, // Haitian Gourde
-    HUF, // Hungarian Forint
-    IDR, // Indonesian Rupiah
-    ILS, // Israeli New Sheqel
-    INR, // Indian Rupee
-    ISK, // Icelandic Króna
-    JMD, // Jamaican Dollar
-    JPY, // Japanese Yen
-    KES, // Kenyan Shilling
-    KGS, // Kyrgyzstani Som
-    KHR, // Cambodian Riel
-    KMF, // Comorian Franc
-    KRW, // South Korean Won
-    KYD, // Cayman Islands Dollar
-    KZT, // Kazakhstani Tenge
-    LAK, // Lao Kip
-    LBP, // Lebanese Pound
-    LKR, // Sri Lankan Rupee
-    LRD, // Liberian Dollar
-    LSL, // Lesotho Loti
-    LTL, // Lithuanian Litas
-    LVL, // Latvian Lats
-    MAD, // Moroccan Dirham
-    MDL, // Moldovan Leu
-    MGA, // Malagasy Ariary
-    MKD, // Macedonian Denar
-    MNT, // Mongolian Tögrög
-    MOP, // Macanese Pataca
-    MRO, // Mauritanian Ouguiya
-    MUR, // Mauritian Rupee
-    MVR, // Maldivian Rufiyaa
-    MWK, // Malawian Kwacha
-    MXN, // Mexican Peso
-    MYR, // Malaysian Ringgit
-    MZN, // Mozambican Metical
-    NAD, // Namibian Dollar
-    NGN, // Nigerian Naira
-    NIO, // Nicaraguan Córdoba
-    NOK, // Norwegian Krone
-    NPR, // Nepalese Rupee
-    NZD, // New Zealand Dollar
-    PAB, // Panamanian Balboa
-    PEN, // Peruvian Nuevo Sol
-    PGK, // Papua New Guinean Kina
-    PHP, // Philippine Peso
-    PKR, // Pakistani Rupee
-    PLN, // Polish Złoty
-    PYG, // Paraguayan Guaraní
-    QAR, // Qatari Riyal
-    RON, // Romanian Leu
-    RSD, // Serbian Dinar
-    RUB, // Russian Ruble
-    RWF, // Rwandan Franc
-    SAR, // Saudi Riyal
-    SBD, // Solomon Islands Dollar
-    SCR, // Seychellois Rupee
-    SEK, // Swedish Krona
-    SGD, // Singapore Dollar
-    SHP, // Saint Helenian Pound
-    SLL, // Sierra Leonean Leone
-    SOS, // Somali Shilling
-    SRD, // Surinamese Dollar
-    STD, // São Tomé and Príncipe Dobra
-    SVC, // Salvadoran Colón
-    SZL, // Swazi Lilangeni
-    THB, // Thai Baht
-    TJS, // Tajikistani Somoni
-    TOP, // Tongan Paʻanga
-    TRY, // Turkish Lira
-    TTD, // Trinidad and Tobago Dollar
-    TWD, // New Taiwan Dollar
-    TZS, // Tanzanian Shilling
-    UAH, // Ukrainian Hryvnia
-    UGX, // Ugandan Shilling
-    USD, // United States Dollar
-    UYU, // Uruguayan Peso
-    UZS, // Uzbekistani Som
-    VEF, // Venezuelan Bolívar
-    VND, // Vietnamese Đồng
-    VUV, // Vanuatu Vatu
-    WST, // Samoan Tala
-    XAF, // Central African Cfa Franc
-    XCD, // East Caribbean Dollar
-    XOF, // West African Cfa Franc
-    XPF, // Cfp Franc
-    YER, // Yemeni Rial
-    ZAR, // South African Rand
-    ZMW, // Zambian Kwacha
+macro_rules! def_currencies {
+    ($($(#[$attr:meta])* $code:ident => ($numeric:literal, $minor:literal, $symbol:literal, $name:literal)),* $(,)?) => {
+        /// Currency is the list of ISO 4217 currencies known to this crate.
+        ///
+        /// Only some of these can be used with Pin Payments, see `Currency::supports_charging`
+        /// and `Currency::supports_settlement`.
+        #[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq, Hash)]
+        pub enum Currency {
+            $($(#[$attr])* #[doc = $name] $code,)*
+        }
+
+        impl Currency {
+            /// The English name of the currency, e.g. `Australian Dollar`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Currency::$code => $name,)*
+                }
+            }
+
+            /// The ISO 4217 numeric code, e.g. `36` for AUD.
+            pub fn numeric_code(&self) -> u16 {
+                match self {
+                    $(Currency::$code => $numeric,)*
+                }
+            }
+
+            /// The number of decimal places between the minor units Pin Payments uses for
+            /// amounts and the major unit, e.g. `2` for AUD (cents) and `0` for JPY.
+            pub fn minor_unit(&self) -> u32 {
+                match self {
+                    $(Currency::$code => $minor,)*
+                }
+            }
+
+            /// The symbol commonly used for the currency in its own locale, e.g. `$` for AUD.
+            /// Symbols are not unique across currencies.
+            pub fn symbol(&self) -> &'static str {
+                match self {
+                    $(Currency::$code => $symbol,)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for Currency {
+            type Err = ParseCurrencyError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($code) => Ok(Currency::$code),)*
+                    _ => Err(ParseCurrencyError(())),
+                }
+            }
+        }
+    };
+}
+
+def_currencies! {
+    AED => (784, 2, "د.إ", "United Arab Emirates Dirham"),
+    AFN => (971, 2, "؋", "Afghan Afghani"),
+    ALL => (8, 2, "L", "Albanian Lek"),
+    AMD => (51, 2, "֏", "Armenian Dram"),
+    ANG => (532, 2, "ƒ", "Netherlands Antillean Gulden"),
+    AOA => (973, 2, "Kz", "Angolan Kwanza"),
+    ARS => (32, 2, "$", "Argentine Peso"),
+    AUD => (36, 2, "$", "Australian Dollar"),
+    AWG => (533, 2, "ƒ", "Aruban Florin"),
+    AZN => (944, 2, "₼", "Azerbaijani Manat"),
+    BAM => (977, 2, "KM", "Bosnia & Herzegovina Convertible Mark"),
+    BBD => (52, 2, "$", "Barbadian Dollar"),
+    BDT => (50, 2, "৳", "Bangladeshi Taka"),
+    BGN => (975, 2, "лв", "Bulgarian Lev"),
+    BIF => (108, 0, "FBu", "Burundian Franc"),
+    BMD => (60, 2, "$", "Bermudian Dollar"),
+    BND => (96, 2, "$", "Brunei Dollar"),
+    BOB => (68, 2, "Bs.", "Bolivian Boliviano"),
+    BRL => (986, 2, "R$", "Brazilian Real"),
+    BSD => (44, 2, "$", "Bahamian Dollar"),
+    BWP => (72, 2, "P", "Botswana Pula"),
+    BZD => (84, 2, "$", "Belize Dollar"),
+    CAD => (124, 2, "$", "Canadian Dollar"),
+    CDF => (976, 2, "FC", "Congolese Franc"),
+    CHF => (756, 2, "CHF", "Swiss Franc"),
+    CLP => (152, 0, "$", "Chilean Peso"),
+    CNY => (156, 2, "¥", "Chinese Renminbi Yuan"),
+    COP => (170, 2, "$", "Colombian Peso"),
+    CRC => (188, 2, "₡", "Costa Rican Colón"),
+    CVE => (132, 2, "Esc", "Cape Verdean Escudo"),
+    CZK => (203, 2, "Kč", "Czech Koruna"),
+    DJF => (262, 0, "Fdj", "Djiboutian Franc"),
+    DKK => (208, 2, "kr", "Danish Krone"),
+    DOP => (214, 2, "$", "Dominican Peso"),
+    DZD => (12, 2, "دج", "Algerian Dinar"),
+    EEK => (233, 2, "kr", "Estonian Kroon"),
+    EGP => (818, 2, "E£", "Egyptian Pound"),
+    ETB => (230, 2, "Br", "Ethiopian Birr"),
+    EUR => (978, 2, "€", "Euro"),
+    FJD => (242, 2, "$", "Fijian Dollar"),
+    FKP => (238, 2, "£", "Falkland Islands Pound"),
+    GBP => (826, 2, "£", "British Pound"),
+    GEL => (981, 2, "₾", "Georgian Lari"),
+    GIP => (292, 2, "£", "Gibraltar Pound"),
+    GMD => (270, 2, "D", "Gambian Dalasi"),
+    GNF => (324, 0, "FG", "Guinean Franc"),
+    GTQ => (320, 2, "Q", "Guatemalan Quetzal"),
+    GYD => (328, 2, "$", "Guyanese Dollar"),
+    HKD => (344, 2, "$", "Hong Kong Dollar"),
+    HNL => (340, 2, "L", "Honduran Lempira"),
+    HRK => (191, 2, "kn", "Croatian Kuna"),
+    HTG => (332, 2, "G", "Haitian Gourde"),
+    HUF => (348, 2, "Ft", "Hungarian Forint"),
+    IDR => (360, 2, "Rp", "Indonesian Rupiah"),
+    ILS => (376, 2, "₪", "Israeli New Sheqel"),
+    INR => (356, 2, "₹", "Indian Rupee"),
+    ISK => (352, 0, "kr", "Icelandic Króna"),
+    JMD => (388, 2, "$", "Jamaican Dollar"),
+    JPY => (392, 0, "¥", "Japanese Yen"),
+    KES => (404, 2, "KSh", "Kenyan Shilling"),
+    KGS => (417, 2, "сом", "Kyrgyzstani Som"),
+    KHR => (116, 2, "៛", "Cambodian Riel"),
+    KMF => (174, 0, "CF", "Comorian Franc"),
+    KRW => (410, 0, "₩", "South Korean Won"),
+    KYD => (136, 2, "$", "Cayman Islands Dollar"),
+    KZT => (398, 2, "₸", "Kazakhstani Tenge"),
+    LAK => (418, 2, "₭", "Lao Kip"),
+    LBP => (422, 2, "ل.ل", "Lebanese Pound"),
+    LKR => (144, 2, "Rs", "Sri Lankan Rupee"),
+    LRD => (430, 2, "$", "Liberian Dollar"),
+    LSL => (426, 2, "L", "Lesotho Loti"),
+    LTL => (440, 2, "Lt", "Lithuanian Litas"),
+    LVL => (428, 2, "Ls", "Latvian Lats"),
+    MAD => (504, 2, "د.م.", "Moroccan Dirham"),
+    MDL => (498, 2, "L", "Moldovan Leu"),
+    MGA => (969, 2, "Ar", "Malagasy Ariary"),
+    MKD => (807, 2, "ден", "Macedonian Denar"),
+    MNT => (496, 2, "₮", "Mongolian Tögrög"),
+    MOP => (446, 2, "MOP$", "Macanese Pataca"),
+    MRO => (478, 2, "UM", "Mauritanian Ouguiya"),
+    MUR => (480, 2, "₨", "Mauritian Rupee"),
+    MVR => (462, 2, "Rf", "Maldivian Rufiyaa"),
+    MWK => (454, 2, "MK", "Malawian Kwacha"),
+    MXN => (484, 2, "$", "Mexican Peso"),
+    MYR => (458, 2, "RM", "Malaysian Ringgit"),
+    MZN => (943, 2, "MT", "Mozambican Metical"),
+    NAD => (516, 2, "$", "Namibian Dollar"),
+    NGN => (566, 2, "₦", "Nigerian Naira"),
+    NIO => (558, 2, "C$", "Nicaraguan Córdoba"),
+    NOK => (578, 2, "kr", "Norwegian Krone"),
+    NPR => (524, 2, "₨", "Nepalese Rupee"),
+    NZD => (554, 2, "$", "New Zealand Dollar"),
+    PAB => (590, 2, "B/.", "Panamanian Balboa"),
+    PEN => (604, 2, "S/", "Peruvian Nuevo Sol"),
+    PGK => (598, 2, "K", "Papua New Guinean Kina"),
+    PHP => (608, 2, "₱", "Philippine Peso"),
+    PKR => (586, 2, "₨", "Pakistani Rupee"),
+    PLN => (985, 2, "zł", "Polish Złoty"),
+    PYG => (600, 0, "₲", "Paraguayan Guaraní"),
+    QAR => (634, 2, "ر.ق", "Qatari Riyal"),
+    RON => (946, 2, "lei", "Romanian Leu"),
+    RSD => (941, 2, "дин.", "Serbian Dinar"),
+    RUB => (643, 2, "₽", "Russian Ruble"),
+    RWF => (646, 0, "FRw", "Rwandan Franc"),
+    SAR => (682, 2, "ر.س", "Saudi Riyal"),
+    SBD => (90, 2, "$", "Solomon Islands Dollar"),
+    SCR => (690, 2, "₨", "Seychellois Rupee"),
+    SEK => (752, 2, "kr", "Swedish Krona"),
+    SGD => (702, 2, "$", "Singapore Dollar"),
+    SHP => (654, 2, "£", "Saint Helenian Pound"),
+    SLL => (694, 2, "Le", "Sierra Leonean Leone"),
+    SOS => (706, 2, "Sh", "Somali Shilling"),
+    SRD => (968, 2, "$", "Surinamese Dollar"),
+    STD => (678, 2, "Db", "São Tomé and Príncipe Dobra"),
+    SVC => (222, 2, "₡", "Salvadoran Colón"),
+    SZL => (748, 2, "L", "Swazi Lilangeni"),
+    THB => (764, 2, "฿", "Thai Baht"),
+    TJS => (972, 2, "SM", "Tajikistani Somoni"),
+    TOP => (776, 2, "T$", "Tongan Paʻanga"),
+    TRY => (949, 2, "₺", "Turkish Lira"),
+    TTD => (780, 2, "$", "Trinidad and Tobago Dollar"),
+    TWD => (901, 2, "NT$", "New Taiwan Dollar"),
+    TZS => (834, 2, "TSh", "Tanzanian Shilling"),
+    UAH => (980, 2, "₴", "Ukrainian Hryvnia"),
+    UGX => (800, 0, "USh", "Ugandan Shilling"),
+    #[default]
+    USD => (840, 2, "$", "United States Dollar"),
+    UYU => (858, 2, "$", "Uruguayan Peso"),
+    UZS => (860, 2, "soʻm", "Uzbekistani Som"),
+    VEF => (937, 2, "Bs", "Venezuelan Bolívar"),
+    VND => (704, 0, "₫", "Vietnamese Đồng"),
+    VUV => (548, 0, "VT", "Vanuatu Vatu"),
+    WST => (882, 2, "T", "Samoan Tala"),
+    XAF => (950, 0, "FCFA", "Central African Cfa Franc"),
+    XCD => (951, 2, "$", "East Caribbean Dollar"),
+    XOF => (952, 0, "CFA", "West African Cfa Franc"),
+    XPF => (953, 0, "₣", "Cfp Franc"),
+    YER => (886, 2, "﷼", "Yemeni Rial"),
+    ZAR => (710, 2, "R", "South African Rand"),
+    ZMW => (967, 2, "K", "Zambian Kwacha"),
 }
 
-impl Default for Currency {
-    fn default() -> Self {
-        Currency::USD
+impl Currency {
+    /// Whether Pin Payments accepts charges in this currency, per the supported currencies
+    /// given in the Pin Payments API reference (<https://pinpayments.com/developers/api-reference/>).
+    /// Pin Payments may add currencies before this list is updated.
+    pub fn supports_charging(&self) -> bool {
+        matches!(
+            self,
+            Currency::AUD | Currency::CAD | Currency::CHF | Currency::EUR | Currency::GBP |
+            Currency::HKD | Currency::JPY | Currency::MYR | Currency::NZD | Currency::SGD |
+            Currency::USD
+        )
+    }
+
+    /// Whether Pin Payments can settle funds to a bank account in this currency, per the same
+    /// API reference.
+    pub fn supports_settlement(&self) -> bool {
+        matches!(self, Currency::AUD)
     }
 }
 
@@ -159,154 +226,6 @@ impl std::fmt::Display for Currency {
     }
 }
 
-impl std::str::FromStr for Currency {
-    type Err = ParseCurrencyError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "AED" => Ok(Currency::AED),
-            "AFN" => Ok(Currency::AFN),
-            "ALL" => Ok(Currency::ALL),
-            "AMD" => Ok(Currency::AMD),
-            "ANG" => Ok(Currency::ANG),
-            "AOA" => Ok(Currency::AOA),
-            "ARS" => Ok(Currency::ARS),
-            "AUD" => Ok(Currency::AUD),
-            "AWG" => Ok(Currency::AWG),
-            "AZN" => Ok(Currency::AZN),
-            "BAM" => Ok(Currency::BAM),
-            "BBD" => Ok(Currency::BBD),
-            "BDT" => Ok(Currency::BDT),
-            "BGN" => Ok(Currency::BGN),
-            "BIF" => Ok(Currency::BIF),
-            "BMD" => Ok(Currency::BMD),
-            "BND" => Ok(Currency::BND),
-            "BOB" => Ok(Currency::BOB),
-            "BRL" => Ok(Currency::BRL),
-            "BSD" => Ok(Currency::BSD),
-            "BWP" => Ok(Currency::BWP),
-            "BZD" => Ok(Currency::BZD),
-            "CAD" => Ok(Currency::CAD),
-            "CDF" => Ok(Currency::CDF),
-            "CHF" => Ok(Currency::CHF),
-            "CLP" => Ok(Currency::CLP),
-            "CNY" => Ok(Currency::CNY),
-            "COP" => Ok(Currency::COP),
-            "CRC" => Ok(Currency::CRC),
-            "CVE" => Ok(Currency::CVE),
-            "CZK" => Ok(Currency::CZK),
-            "DJF" => Ok(Currency::DJF),
-            "DKK" => Ok(Currency::DKK),
-            "DOP" => Ok(Currency::DOP),
-            "DZD" => Ok(Currency::DZD),
-            "EEK" => Ok(Currency::EEK),
-            "EGP" => Ok(Currency::EGP),
-            "ETB" => Ok(Currency::ETB),
-            "EUR" => Ok(Currency::EUR),
-            "FJD" => Ok(Currency::FJD),
-            "FKP" => Ok(Currency::FKP),
-            "GBP" => Ok(Currency::GBP),
-            "GEL" => Ok(Currency::GEL),
-            "GIP" => Ok(Currency::GIP),
-            "GMD" => Ok(Currency::GMD),
-            "GNF" => Ok(Currency::GNF),
-            "GTQ" => Ok(Currency::GTQ),
-            "GYD" => Ok(Currency::GYD),
-            "HKD" => Ok(Currency::HKD),
-            "HNL" => Ok(Currency::HNL),
-            "HRK" => Ok(Currency::HRK),
-            "HTG" => Ok(Currency::HTG),
-            "HUF" => Ok(Currency::HUF),
-            "IDR" => Ok(Currency::IDR),
-            "ILS" => Ok(Currency::ILS),
-            "INR" => Ok(Currency::INR),
-            "ISK" => Ok(Currency::ISK),
-            "JMD" => Ok(Currency::JMD),
-            "JPY" => Ok(Currency::JPY),
-            "KES" => Ok(Currency::KES),
-            "KGS" => Ok(Currency::KGS),
-            "KHR" => Ok(Currency::KHR),
-            "KMF" => Ok(Currency::KMF),
-            "KRW" => Ok(Currency::KRW),
-            "KYD" => Ok(Currency::KYD),
-            "KZT" => Ok(Currency::KZT),
-            "LAK" => Ok(Currency::LAK),
-            "LBP" => Ok(Currency::LBP),
-            "LKR" => Ok(Currency::LKR),
-            "LRD" => Ok(Currency::LRD),
-            "LSL" => Ok(Currency::LSL),
-            "LTL" => Ok(Currency::LTL),
-            "LVL" => Ok(Currency::LVL),
-            "MAD" => Ok(Currency::MAD),
-            "MDL" => Ok(Currency::MDL),
-            "MGA" => Ok(Currency::MGA),
-            "MKD" => Ok(Currency::MKD),
-            "MNT" => Ok(Currency::MNT),
-            "MOP" => Ok(Currency::MOP),
-            "MRO" => Ok(Currency::MRO),
-            "MUR" => Ok(Currency::MUR),
-            "MVR" => Ok(Currency::MVR),
-            "MWK" => Ok(Currency::MWK),
-            "MXN" => Ok(Currency::MXN),
-            "MYR" => Ok(Currency::MYR),
-            "MZN" => Ok(Currency::MZN),
-            "NAD" => Ok(Currency::NAD),
-            "NGN" => Ok(Currency::NGN),
-            "NIO" => Ok(Currency::NIO),
-            "NOK" => Ok(Currency::NOK),
-            "NPR" => Ok(Currency::NPR),
-            "NZD" => Ok(Currency::NZD),
-            "PAB" => Ok(Currency::PAB),
-            "PEN" => Ok(Currency::PEN),
-            "PGK" => Ok(Currency::PGK),
-            "PHP" => Ok(Currency::PHP),
-            "PKR" => Ok(Currency::PKR),
-            "PLN" => Ok(Currency::PLN),
-            "PYG" => Ok(Currency::PYG),
-            "QAR" => Ok(Currency::QAR),
-            "RON" => Ok(Currency::RON),
-            "RSD" => Ok(Currency::RSD),
-            "RUB" => Ok(Currency::RUB),
-            "RWF" => Ok(Currency::RWF),
-            "SAR" => Ok(Currency::SAR),
-            "SBD" => Ok(Currency::SBD),
-            "SCR" => Ok(Currency::SCR),
-            "SEK" => Ok(Currency::SEK),
-            "SGD" => Ok(Currency::SGD),
-            "SHP" => Ok(Currency::SHP),
-            "SLL" => Ok(Currency::SLL),
-            "SOS" => Ok(Currency::SOS),
-            "SRD" => Ok(Currency::SRD),
-            "STD" => Ok(Currency::STD),
-            "SVC" => Ok(Currency::SVC),
-            "SZL" => Ok(Currency::SZL),
-            "THB" => Ok(Currency::THB),
-            "TJS" => Ok(Currency::TJS),
-            "TOP" => Ok(Currency::TOP),
-            "TRY" => Ok(Currency::TRY),
-            "TTD" => Ok(Currency::TTD),
-            "TWD" => Ok(Currency::TWD),
-            "TZS" => Ok(Currency::TZS),
-            "UAH" => Ok(Currency::UAH),
-            "UGX" => Ok(Currency::UGX),
-            "USD" => Ok(Currency::USD),
-            "UYU" => Ok(Currency::UYU),
-            "UZS" => Ok(Currency::UZS),
-            "VEF" => Ok(Currency::VEF),
-            "VND" => Ok(Currency::VND),
-            "VUV" => Ok(Currency::VUV),
-            "WST" => Ok(Currency::WST),
-            "XAF" => Ok(Currency::XAF),
-            "XCD" => Ok(Currency::XCD),
-            "XOF" => Ok(Currency::XOF),
-            "XPF" => Ok(Currency::XPF),
-            "YER" => Ok(Currency::YER),
-            "ZAR" => Ok(Currency::ZAR),
-            "ZMW" => Ok(Currency::ZMW),
-            _ => Err(ParseCurrencyError(())),
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct ParseCurrencyError(/* private */ ());
 
@@ -322,3 +241,37 @@ impl std::error::Error for ParseCurrencyError {
         "unknown currency code"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Currency;
+
+    #[test]
+    fn metadata() {
+        assert_eq!(Currency::AUD.name(), "Australian Dollar");
+        assert_eq!(Currency::AUD.numeric_code(), 36);
+        assert_eq!(Currency::AUD.minor_unit(), 2);
+        assert_eq!(Currency::AUD.symbol(), "$");
+
+        assert_eq!(Currency::JPY.minor_unit(), 0);
+        assert_eq!(Currency::EUR.symbol(), "€");
+        assert_eq!(Currency::ALL.numeric_code(), 8);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("NZD".parse::<Currency>().ok(), Some(Currency::NZD));
+        assert!("nzd".parse::<Currency>().is_err());
+        assert!("XYZ".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn pin_supported() {
+        assert!(Currency::AUD.supports_charging());
+        assert!(Currency::USD.supports_charging());
+        assert!(!Currency::VEF.supports_charging());
+
+        assert!(Currency::AUD.supports_settlement());
+        assert!(!Currency::USD.supports_settlement());
+    }
+}
@@ -3,9 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response, StatusOnlyResponse};
 use crate::error::PinError;
-use crate::ids::{CardId, CustomerId};
+use crate::ids::{CardId, CustomerId, PaymentSourceId, SubscriptionId};
 use crate::params::{unpack_contained, Page, Paginator, paginate};
-use crate::resources::{CardParams, Card, Charge};
+use crate::resources::{CardParams, Card, Charge, Subscription};
 use crate::build_map;
 
 #[derive(Clone, Debug, Default, Serialize)]
@@ -31,9 +31,46 @@ pub struct CreateCustomer<'a> {
     pub card: Option<CardParams<'a>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub card_token: Option<CardId>
+    pub card_token: Option<CardId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_source_token: Option<PaymentSourceId>
+}
+
+#[derive(Debug, Serialize)]
+struct CreateCardFromPaymentSource<'a> {
+    payment_source_token: &'a PaymentSourceId
 }
 
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateCustomer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardParams<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_token: Option<CardId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_card_token: Option<CardId>
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Customer {
@@ -64,7 +101,7 @@ impl Customer {
         client.get_query("/customers", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Customer, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Customer, PinError>> {
         paginate(
             move |page, per_page| {
                 Customer::list(client, Some(page), Some(per_page))
@@ -77,6 +114,10 @@ impl Customer {
         unpack_contained(client.get(&format!("/customers/{}", token)))
     }
 
+    pub fn update(client: &Client, token: &CustomerId, params: UpdateCustomer<'_>) -> Response<Customer> {
+        unpack_contained(client.put_form(&format!("/customers/{}", token), &params))
+    }
+
     pub fn delete(client: &Client, token: &CustomerId) -> StatusOnlyResponse { 
         client.delete_status_only(&format!("/customers/{}", token))
     }
@@ -123,7 +164,35 @@ impl Customer {
         unpack_contained(client.post_form(&format!("/customers/{}/cards", token), &params))
     }
 
+    pub fn create_card_from_payment_source(client: &Client, token: &CustomerId, payment_source_token: &PaymentSourceId) -> Response<Card> {
+        let params = CreateCardFromPaymentSource { payment_source_token };
+        unpack_contained(client.post_form(&format!("/customers/{}/cards", token), &params))
+    }
+
     pub fn delete_card(client: &Client, token: &CustomerId, card_token: &CardId) -> StatusOnlyResponse { 
         client.delete_status_only(&format!("/customers/{}/cards/{}", token, card_token))
     }
+
+    pub fn list_subscriptions(client: &Client, token: &CustomerId, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Subscription>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query(&format!("/customers/{}/subscriptions", token), &params)
+    }
+
+    pub fn list_subscriptions_with_paginator<'a>(client: &'a Client, token: &'a CustomerId, per_page: Option<u32>) -> Paginator<'a, Result<Subscription, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Customer::list_subscriptions(client, token, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn delete_subscription(client: &Client, token: &CustomerId, subscription_token: &SubscriptionId) -> Response<Subscription> {
+        unpack_contained(client.delete(&format!("/customers/{}/subscriptions/{}", token, subscription_token)))
+    }
 }
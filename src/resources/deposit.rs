@@ -0,0 +1,78 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize};
+
+use crate::client::{Client, Response};
+use crate::error::PinError;
+use crate::ids::{DepositId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::resources::{BankAccount, Money, TransferLineItem};
+use crate::build_map;
+
+/// Deposit line items share their shape with transfer line items.
+pub type DepositLineItem = TransferLineItem;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Deposit {
+    pub token: DepositId,
+    pub status: String,
+    /// The `amount` and `currency` of the deposit.
+    #[serde(flatten)]
+    pub money: Money,
+    pub total_debits: i64,
+    pub total_credits: i64,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub paid_at: Option<OffsetDateTime>,
+
+    pub reference: Option<String>,
+    pub line_items_count: i32,
+
+    pub bank_account: Option<BankAccount>
+}
+
+impl Deposit {
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Deposit>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/deposits", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Deposit, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Deposit::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &DepositId) -> Response<Deposit> {
+        unpack_contained(client.get(&format!("/deposits/{}", token)))
+    }
+
+    pub fn line_items(client: &Client, token: &DepositId, page: Option<u32>, per_page: Option<u32>) -> Response<Page<DepositLineItem>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query(&format!("/deposits/{}/line_items", token), &params)
+    }
+
+    pub fn line_items_with_paginator<'a>(client: &'a Client, token: &'a DepositId, per_page: Option<u32>) -> Paginator<'a, Result<DepositLineItem, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Deposit::line_items(client, token, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+}
@@ -3,19 +3,46 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response, StatusOnlyResponse};
 use crate::error::PinError;
-use crate::ids::{DisputeId};
+use crate::ids::{DisputeId, FileId};
 use crate::params::{unpack_contained, SortDirection, Page, Paginator, paginate};
-use crate::resources::{Currency, Charge};
+use crate::resources::{Money, Charge};
 use crate::build_map;
 
 
+def_string_enum! {
+    /// The reason given by the cardholder's bank for a dispute.
+    pub enum DisputeCategory {
+        General => "general",
+        Fraudulent => "fraudulent",
+        Duplicate => "duplicate",
+        ProductNotReceived => "product_not_received",
+        ProductUnacceptable => "product_unacceptable",
+        SubscriptionCancelled => "subscription_cancelled",
+        CreditNotProcessed => "credit_not_processed",
+        Unrecognised => "unrecognised",
+    }
+}
+
+def_string_enum! {
+    /// Where a dispute is in its lifecycle.
+    pub enum DisputeStatus {
+        EvidenceRequired => "evidence_required",
+        EvidenceSubmitted => "evidence_submitted",
+        Accepted => "accepted",
+        Won => "won",
+        Lost => "lost",
+        Cancelled => "cancelled",
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct Dispute {
     pub token: DisputeId,
-    pub category: String,
-    pub status: String,
-    pub amount: i64,
-    pub currency: Currency,
+    pub category: DisputeCategory,
+    pub status: DisputeStatus,
+    /// The `amount` and `currency` of the dispute.
+    #[serde(flatten)]
+    pub money: Money,
     pub charge: Charge,
 
     #[serde(with = "time::serde::iso8601::option")]
@@ -26,17 +53,83 @@ pub struct Dispute {
     pub received_at: Option<OffsetDateTime>
 }
 
+/// The details and supporting files given for one category of evidence.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DisputeEvidenceItem {
+    pub details: Option<String>,
+
+    #[serde(default)]
+    pub files: Vec<FileId>
+}
+
+/// The evidence gathered so far for a dispute. Only the categories listed in the dispute's
+/// `relevant_evidence` are considered.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DisputeEvidence {
+    pub proof_of_delivery_or_service: Option<DisputeEvidenceItem>,
+    pub invoice_or_receipt: Option<DisputeEvidenceItem>,
+    pub invoice_showing_distinct_transactions: Option<DisputeEvidenceItem>,
+    pub customer_communication: Option<DisputeEvidenceItem>,
+    pub refund_or_cancellation_policy: Option<DisputeEvidenceItem>,
+    pub recurring_transaction_agreement: Option<DisputeEvidenceItem>,
+    pub additional_evidence: Option<DisputeEvidenceItem>
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateDisputeEvidenceItem<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<FileId>
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateDisputeEvidence<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_of_delivery_or_service: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_or_receipt: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_showing_distinct_transactions: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_or_cancellation_policy: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_transaction_agreement: Option<UpdateDisputeEvidenceItem<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_evidence: Option<UpdateDisputeEvidenceItem<'a>>
+}
+
+/// An entry in the history of a dispute, such as evidence being submitted or the outcome
+/// being decided.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DisputeActivity {
+    pub action: String,
+    pub description: Option<String>,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct DisputeSearchParams<'a> {
     pub query: Option<&'a str>,
     pub status: Option<&'a str>,
-    pub sort: Option<SortByField>,
+    pub sort: Option<DisputeSortByField>,
     pub direction: Option<SortDirection>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
-pub enum SortByField {
+pub enum DisputeSortByField {
     ReceivedAt,
     EvidenceRequiredBy,
     Amount
@@ -53,7 +146,7 @@ impl Dispute {
         client.get_query("/disputes", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Dispute, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Dispute, PinError>> {
         paginate(
             move |page, per_page| {
                 Dispute::list(client, Some(page), Some(per_page))
@@ -70,6 +163,20 @@ impl Dispute {
         unpack_contained(client.get(&format!("/disputes/{}", token)))
     }
 
+    pub fn evidence(client: &Client, token: &DisputeId) -> Response<DisputeEvidence> {
+        unpack_contained(client.get(&format!("/disputes/{}/evidence", token)))
+    }
+
+    /// Save evidence against a dispute without submitting it. Categories left as `None` are
+    /// unchanged.
+    pub fn update_evidence(client: &Client, token: &DisputeId, params: UpdateDisputeEvidence<'_>) -> Response<DisputeEvidence> {
+        unpack_contained(client.put_form(&format!("/disputes/{}/evidence", token), &params))
+    }
+
+    pub fn activity(client: &Client, token: &DisputeId) -> Response<Vec<DisputeActivity>> {
+        unpack_contained(client.get(&format!("/disputes/{}/activity", token)))
+    }
+
     pub fn submit_evidence(client: &Client, token: &DisputeId) -> StatusOnlyResponse {
         client.post_status_only(&format!("/disputes/{}/evidence", token))
     }
@@ -0,0 +1,133 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize};
+
+use crate::client::{Client, Response};
+use crate::error::PinError;
+use crate::ids::{EventId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::resources::{Charge, Customer, Dispute, Recipient, Refund, Subscription, Transfer};
+use crate::build_map;
+
+macro_rules! def_event_data {
+    ($($event_type:literal => $variant:ident($resource:ty)),* $(,)?) => {
+        /// The object an event relates to, typed according to the event's `type`.
+        ///
+        /// Event types this crate does not know about are kept as `Unknown` along with
+        /// their raw JSON.
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub enum EventData {
+            $($variant($resource),)*
+            Unknown { r#type: String, data: serde_json::Value },
+        }
+
+        impl EventData {
+            /// The event type, e.g. `charge.succeeded`.
+            pub fn event_type(&self) -> &str {
+                match self {
+                    $(EventData::$variant(_) => $event_type,)*
+                    EventData::Unknown { r#type, .. } => r#type,
+                }
+            }
+
+            pub(crate) fn from_parts(r#type: String, data: serde_json::Value) -> Result<Self, serde_json::Error> {
+                match r#type.as_str() {
+                    $($event_type => serde_json::from_value(data).map(EventData::$variant),)*
+                    _ => Ok(EventData::Unknown { r#type, data }),
+                }
+            }
+        }
+    };
+}
+
+def_event_data! {
+    "charge.authorised" => ChargeAuthorised(Charge),
+    "charge.captured" => ChargeCaptured(Charge),
+    "charge.failed" => ChargeFailed(Charge),
+    "charge.succeeded" => ChargeSucceeded(Charge),
+    "charge.voided" => ChargeVoided(Charge),
+    "customer.created" => CustomerCreated(Customer),
+    "customer.updated" => CustomerUpdated(Customer),
+    "customer.deleted" => CustomerDeleted(Customer),
+    "dispute.created" => DisputeCreated(Dispute),
+    "dispute.evidence_required" => DisputeEvidenceRequired(Dispute),
+    "dispute.evidence_submitted" => DisputeEvidenceSubmitted(Dispute),
+    "dispute.accepted" => DisputeAccepted(Dispute),
+    "dispute.won" => DisputeWon(Dispute),
+    "dispute.lost" => DisputeLost(Dispute),
+    "dispute.cancelled" => DisputeCancelled(Dispute),
+    "recipient.created" => RecipientCreated(Recipient),
+    "recipient.updated" => RecipientUpdated(Recipient),
+    "recipient.deleted" => RecipientDeleted(Recipient),
+    "refund.created" => RefundCreated(Refund),
+    "refund.succeeded" => RefundSucceeded(Refund),
+    "refund.failed" => RefundFailed(Refund),
+    "subscription.created" => SubscriptionCreated(Subscription),
+    "subscription.renewed" => SubscriptionRenewed(Subscription),
+    "subscription.renewal_failed" => SubscriptionRenewalFailed(Subscription),
+    "subscription.cancelled" => SubscriptionCancelled(Subscription),
+    "subscription.expired" => SubscriptionExpired(Subscription),
+    "transfer.created" => TransferCreated(Transfer),
+    "transfer.failed" => TransferFailed(Transfer),
+    "transfer.paid" => TransferPaid(Transfer),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "RawEvent")]
+pub struct Event {
+    pub token: EventId,
+    pub data: EventData,
+    pub created_at: Option<OffsetDateTime>
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    token: EventId,
+    r#type: String,
+    data: serde_json::Value,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    created_at: Option<OffsetDateTime>
+}
+
+impl TryFrom<RawEvent> for Event {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawEvent) -> Result<Self, Self::Error> {
+        Ok(Event {
+            token: raw.token,
+            data: EventData::from_parts(raw.r#type, raw.data)?,
+            created_at: raw.created_at
+        })
+    }
+}
+
+impl Event {
+    /// The event type, e.g. `charge.succeeded`.
+    pub fn event_type(&self) -> &str {
+        self.data.event_type()
+    }
+
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Event>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/events", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Event, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Event::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &EventId) -> Response<Event> {
+        unpack_contained(client.get(&format!("/events/{}", token)))
+    }
+}
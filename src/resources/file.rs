@@ -1,10 +1,20 @@
+use std::path::Path;
+use futures::io::{AsyncRead, AsyncReadExt};
 use time::{OffsetDateTime};
 use serde::{Deserialize};
 
-use crate::client::{Client, Response, StatusOnlyResponse};
+use crate::client::{Client, Multipart, Response, StatusOnlyResponse};
+use crate::error::PinError;
 use crate::ids::{FileId};
 use crate::params::{unpack_contained};
 
+def_string_enum! {
+    /// What an uploaded file will be used for.
+    pub enum FilePurpose {
+        DisputeEvidence => "dispute_evidence",
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct File {
     pub token: FileId,
@@ -14,7 +24,7 @@ pub struct File {
     #[serde(with = "time::serde::iso8601::option")]
     pub presigned_url_expires_at: Option<OffsetDateTime>,
 
-    pub purpose: String, 
+    pub purpose: FilePurpose,
     pub size: u32,
     pub mime_type: String,
 
@@ -24,6 +34,47 @@ pub struct File {
 
 
 impl File {
+    /// Upload `contents` as a file named `filename`. The mime type is guessed from the
+    /// extension of `filename`.
+    pub fn create(client: &Client, purpose: FilePurpose, filename: &str, contents: &[u8]) -> Response<File> {
+        let form = Multipart::new()
+            .text("purpose", purpose.as_str())
+            .file("file", filename, mime_type(filename), contents);
+        unpack_contained(client.post_multipart("/files", form))
+    }
+
+    /// Upload the file at `path`, which is read into memory before sending.
+    pub fn create_from_path(client: &Client, purpose: FilePurpose, path: impl AsRef<Path>) -> Response<File> {
+        let client = client.clone();
+        let path = path.as_ref().to_path_buf();
+
+        Box::pin(async move {
+            let contents = ::async_std::fs::read(&path).await
+                .map_err(|e| PinError::ClientError(format!("unable to read {}: {}", path.display(), e)))?;
+            let filename = path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            File::create(&client, purpose, &filename, &contents).await
+        })
+    }
+
+    /// Upload the contents of `reader` as a file named `filename`. The reader is consumed
+    /// into memory before sending.
+    pub fn create_from_reader<R>(client: &Client, purpose: FilePurpose, filename: &str, mut reader: R) -> Response<File>
+    where
+        R: AsyncRead + Unpin + Send + 'static
+    {
+        let client = client.clone();
+        let filename = filename.to_string();
+
+        Box::pin(async move {
+            let mut contents = Vec::new();
+            reader.read_to_end(&mut contents).await
+                .map_err(|e| PinError::ClientError(format!("unable to read {}: {}", filename, e)))?;
+            File::create(&client, purpose, &filename, &contents).await
+        })
+    }
+
     pub fn retrieve(client: &Client, token: &FileId) -> Response<File> {
         unpack_contained(client.get(&format!("/files/{}", token)))
     }
@@ -32,3 +83,19 @@ impl File {
         client.delete_status_only(&format!("/files/{}", token))
     }
 }
+
+fn mime_type(filename: &str) -> &'static str {
+    let extension = Path::new(filename)
+        .extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "txt" => "text/plain",
+        _ => "application/octet-stream"
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use time::{OffsetDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response};
+use crate::error::PinError;
+use crate::ids::{MerchantId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::resources::{CreateBankAccount};
+use crate::build_map;
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MerchantContact<'a> {
+    pub first_name: &'a str,
+    pub last_name: &'a str,
+    pub phone_number: &'a str,
+    pub email: &'a str,
+    pub password: &'a str
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MerchantEntity<'a> {
+    pub business_registration_number: &'a str,
+    pub full_legal_name: &'a str,
+    pub address_line_1: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line_2: Option<&'a str>,
+
+    pub address_locality: &'a str,
+    pub address_region: &'a str,
+    pub address_postal_code: &'a str
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MerchantBusiness<'a> {
+    pub trading_name: &'a str,
+    pub description: &'a str,
+    pub typical_product_price: i64,
+    pub transactions_per_month: i64,
+    pub annual_transaction_volume: i64,
+    pub sells_physical_goods: bool,
+    pub average_delivery_days: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MerchantDirector<'a> {
+    pub full_name: &'a str,
+    pub contact_number: &'a str,
+    pub date_of_birth: &'a str
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateMerchant<'a> {
+    pub contact: MerchantContact<'a>,
+    pub entity: MerchantEntity<'a>,
+    pub business: MerchantBusiness<'a>,
+    pub bank_account: CreateBankAccount<'a>,
+    pub director: MerchantDirector<'a>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<&'a str>
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Merchant {
+    pub token: MerchantId,
+    pub email: String,
+    pub business_name: String,
+    pub status: String,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub updated_at: Option<OffsetDateTime>
+}
+
+/// The settings applied to newly created merchants, as configured for the partner account.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MerchantDefaultSettings {
+    #[serde(flatten)]
+    pub settings: HashMap<String, serde_json::Value>
+}
+
+impl Merchant {
+    pub fn create(client: &Client, params: CreateMerchant<'_>) -> Response<Merchant> {
+        unpack_contained(client.post_form("/merchants", &params))
+    }
+
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Merchant>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/merchants", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Merchant, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Merchant::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &MerchantId) -> Response<Merchant> {
+        unpack_contained(client.get(&format!("/merchants/{}", token)))
+    }
+
+    pub fn default_settings(client: &Client) -> Response<MerchantDefaultSettings> {
+        unpack_contained(client.get("/merchants/default_settings"))
+    }
+}
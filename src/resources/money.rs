@@ -0,0 +1,147 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::Currency;
+
+/// An amount in the minor units of its currency, as used throughout the Pin Payments API.
+///
+/// `Money` serializes as `{"amount": .., "currency": ..}`, the same shape Pin Payments uses.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub struct Money {
+    pub amount: i64,
+    pub currency: Currency
+}
+
+impl Money {
+    /// Create an amount from minor units, e.g. cents for AUD.
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    /// Parse an amount given in major units, e.g. `"12.34"` AUD is 1234 cents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseMoneyError` if `value` is not a decimal number, has more decimal places
+    /// than the currency allows, or does not fit in an `i64` of minor units.
+    pub fn from_major(value: &str, currency: Currency) -> Result<Self, ParseMoneyError> {
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, fraction),
+            None => (digits, ""),
+        };
+
+        let exponent = currency.minor_unit() as usize;
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !all_digits(whole) || !all_digits(fraction) || (digits.contains('.') && fraction.is_empty()) {
+            return Err(ParseMoneyError("invalid decimal amount"));
+        }
+        if fraction.len() > exponent {
+            return Err(ParseMoneyError("too many decimal places for currency"));
+        }
+
+        let minor = format!("{}{:0<width$}", whole, fraction, width = exponent);
+        let amount: i64 = minor.parse().map_err(|_| ParseMoneyError("amount out of range"))?;
+
+        Ok(Money::new(if negative { -amount } else { amount }, currency))
+    }
+
+    /// Format the amount in major units without a currency, e.g. `"12.34"`.
+    pub fn to_major_string(&self) -> String {
+        let exponent = self.currency.minor_unit();
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let magnitude = self.amount.unsigned_abs();
+
+        if exponent == 0 {
+            return format!("{}{}", sign, magnitude);
+        }
+
+        let scale = 10u64.pow(exponent);
+        format!("{}{}.{:0width$}", sign, magnitude / scale, magnitude % scale, width = exponent as usize)
+    }
+
+    /// Add two amounts, returning `None` if the currencies differ or the result overflows.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount.checked_add(other.amount).map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// Subtract `other`, returning `None` if the currencies differ or the result overflows.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount.checked_sub(other.amount).map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// Multiply by a quantity, returning `None` if the result overflows.
+    pub fn checked_mul(self, quantity: i64) -> Option<Money> {
+        self.amount.checked_mul(quantity).map(|amount| Money::new(amount, self.currency))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?}", self.to_major_string(), self.currency)
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseMoneyError(/* private */ &'static str);
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::Money;
+    use crate::resources::Currency;
+
+    #[test]
+    fn parses_major_units() {
+        assert_eq!(Money::from_major("12.34", Currency::AUD).ok(), Some(Money::new(1234, Currency::AUD)));
+        assert_eq!(Money::from_major("12.3", Currency::AUD).ok(), Some(Money::new(1230, Currency::AUD)));
+        assert_eq!(Money::from_major("12", Currency::AUD).ok(), Some(Money::new(1200, Currency::AUD)));
+        assert_eq!(Money::from_major("-0.05", Currency::AUD).ok(), Some(Money::new(-5, Currency::AUD)));
+        assert_eq!(Money::from_major("500", Currency::JPY).ok(), Some(Money::new(500, Currency::JPY)));
+
+        assert!(Money::from_major("12.345", Currency::AUD).is_err());
+        assert!(Money::from_major("500.5", Currency::JPY).is_err());
+        assert!(Money::from_major("12.", Currency::AUD).is_err());
+        assert!(Money::from_major(".5", Currency::AUD).is_err());
+        assert!(Money::from_major("1,000", Currency::AUD).is_err());
+        assert!(Money::from_major("99999999999999999999", Currency::AUD).is_err());
+    }
+
+    #[test]
+    fn formats_major_units() {
+        assert_eq!(Money::new(1234, Currency::AUD).to_major_string(), "12.34");
+        assert_eq!(Money::new(-5, Currency::AUD).to_major_string(), "-0.05");
+        assert_eq!(Money::new(500, Currency::JPY).to_major_string(), "500");
+        assert_eq!(Money::new(i64::MIN, Currency::USD).to_major_string(), "-92233720368547758.08");
+        assert_eq!(Money::new(1234, Currency::AUD).to_string(), "12.34 AUD");
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let ten = Money::new(1000, Currency::AUD);
+
+        assert_eq!(ten.checked_add(ten), Some(Money::new(2000, Currency::AUD)));
+        assert_eq!(ten.checked_sub(Money::new(1500, Currency::AUD)), Some(Money::new(-500, Currency::AUD)));
+        assert_eq!(ten.checked_mul(3), Some(Money::new(3000, Currency::AUD)));
+
+        assert_eq!(ten.checked_add(Money::new(1000, Currency::NZD)), None);
+        assert_eq!(Money::new(i64::MAX, Currency::AUD).checked_add(Money::new(1, Currency::AUD)), None);
+        assert_eq!(ten.checked_mul(i64::MAX), None);
+    }
+}
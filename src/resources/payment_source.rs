@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response};
+use crate::ids::{PaymentSourceId};
+use crate::params::{unpack_contained};
+use crate::resources::{CardParams, CardScheme};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PaymentSourceType {
+    Card,
+    #[serde(rename = "applepay")]
+    ApplePay,
+    #[serde(rename = "googlepay")]
+    GooglePay,
+    NetworkToken
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ApplePayHeader<'a> {
+    #[serde(rename = "ephemeralPublicKey")]
+    pub ephemeral_public_key: &'a str,
+    #[serde(rename = "publicKeyHash")]
+    pub public_key_hash: &'a str,
+    #[serde(rename = "transactionId")]
+    pub transaction_id: &'a str
+}
+
+/// The `paymentData` of an Apple Pay payment token.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ApplePayPaymentData<'a> {
+    pub data: &'a str,
+    pub signature: &'a str,
+    pub version: &'a str,
+    pub header: ApplePayHeader<'a>
+}
+
+/// The `tokenizationData` token of a Google Pay payment.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GooglePayPaymentData<'a> {
+    pub signature: &'a str,
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: &'a str,
+    #[serde(rename = "signedMessage")]
+    pub signed_message: &'a str
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct NetworkTokenParams<'a> {
+    pub number: &'a str,
+    pub expiry_month: &'a str,
+    pub expiry_year: &'a str,
+    pub cryptogram: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eci: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", content = "source")]
+pub enum CreatePaymentSource<'a> {
+    #[serde(rename = "card")]
+    Card(CardParams<'a>),
+    #[serde(rename = "applepay")]
+    ApplePay(ApplePayPaymentData<'a>),
+    #[serde(rename = "googlepay")]
+    GooglePay(GooglePayPaymentData<'a>),
+    #[serde(rename = "network_token")]
+    NetworkToken(NetworkTokenParams<'a>)
+}
+
+/// The card details behind a payment source, as far as Pin Payments reveals them.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PaymentSourceDetails {
+    pub scheme: Option<CardScheme>,
+    pub display_number: Option<String>,
+    pub expiry_month: Option<i64>,
+    pub expiry_year: Option<i64>,
+    pub name: Option<String>
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PaymentSource {
+    pub token: PaymentSourceId,
+    pub r#type: PaymentSourceType,
+
+    #[serde(default)]
+    pub source: PaymentSourceDetails
+}
+
+impl PaymentSource {
+    pub fn create(client: &Client, params: CreatePaymentSource<'_>) -> Response<PaymentSource> {
+        unpack_contained(client.post_form("/payment_sources", &params))
+    }
+
+    pub fn retrieve(client: &Client, token: &PaymentSourceId) -> Response<PaymentSource> {
+        unpack_contained(client.get(&format!("/payment_sources/{}", token)))
+    }
+}
@@ -5,7 +5,7 @@ use crate::client::{Client, Response, StatusOnlyResponse};
 use crate::error::PinError;
 use crate::ids::{PlanId};
 use crate::params::{unpack_contained, Page, Paginator, paginate};
-use crate::resources::{Currency};
+use crate::resources::{Currency, Money};
 use crate::build_map;
 
 #[derive(PartialEq, Debug, Serialize, Default, Deserialize)]
@@ -51,6 +51,18 @@ pub struct CreatePlan<'a> {
     pub customer_permissions: Option<Vec<CustomerPermission>>
 }
 
+#[derive(Debug, Default, Serialize)]
+pub struct UpdatePlan<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    /// Replaces the plan's customer permissions. The form encoding sends nothing for an empty
+    /// list, so `Some(vec![])` leaves the permissions unchanged; removing every permission is
+    /// not supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_permissions: Option<Vec<CustomerPermission>>
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct SubscriptionCounts {
     pub trial: u32,
@@ -63,8 +75,9 @@ pub struct SubscriptionCounts {
 pub struct Plan {
     pub token: PlanId,
     pub name: String,
-    pub amount: i64,
-    pub currency: Currency,
+    /// The `amount` and `currency` of the plan.
+    #[serde(flatten)]
+    pub money: Money,
     pub interval: u32,
     pub interval_unit: IntervalUnit,
     pub intervals: u32,
@@ -94,7 +107,7 @@ impl Plan {
         client.get_query("/plans", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Plan, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Plan, PinError>> {
         paginate(
             move |page, per_page| {
                 Plan::list(client, Some(page), Some(per_page))
@@ -107,6 +120,10 @@ impl Plan {
         unpack_contained(client.get(&format!("/plans/{}", token)))
     }
 
+    pub fn update(client: &Client, token: &PlanId, params: UpdatePlan<'_>) -> Response<Plan> {
+        unpack_contained(client.put_form(&format!("/plans/{}", token), &params))
+    }
+
     pub fn delete(client: &Client, token: &PlanId) -> StatusOnlyResponse { 
         client.delete_status_only(&format!("/plans/{}", token))
     }
@@ -1,9 +1,9 @@
 use time::{OffsetDateTime};
 use serde::{Deserialize, Serialize};
 use crate::ids::{RecipientId, BankAccountId};
-use crate::resources::{CreateBankAccount, BankAccount};
-use crate::params::{Page, unpack_contained};
-use crate::{Client, Response};
+use crate::resources::{CreateBankAccount, BankAccount, Transfer};
+use crate::params::{Page, Paginator, unpack_contained, paginate};
+use crate::{Client, Response, PinError};
 use crate::build_map;
 
 #[derive(Clone, Debug, Default, Serialize)]
@@ -15,6 +15,21 @@ pub struct CreateRecipient<'a> {
     pub bank_account_token: Option<BankAccountId>
 }
 
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateRecipient<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account: Option<CreateBankAccount<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_token: Option<BankAccountId>
+}
+
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct Recipient {
     pub token: RecipientId,
@@ -29,7 +44,7 @@ pub struct Recipient {
 
 impl Recipient {
     pub fn create(client: &Client, params: CreateRecipient<'_>) -> Response<Recipient> {
-        unpack_contained(client.post_form(&format!("/recipients"), &params))
+        unpack_contained(client.post_form("/recipients", &params))
     }
 
     pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Recipient>> {
@@ -42,7 +57,39 @@ impl Recipient {
         client.get_query("/recipients", &params)
     }
 
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Recipient, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Recipient::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
     pub fn retrieve(client: &Client, token: &RecipientId) -> Response<Recipient> {
         unpack_contained(client.get(&format!("/recipients/{}", token)))
     }
+
+    pub fn update(client: &Client, token: &RecipientId, params: UpdateRecipient<'_>) -> Response<Recipient> {
+        unpack_contained(client.put_form(&format!("/recipients/{}", token), &params))
+    }
+
+    pub fn list_transfers(client: &Client, token: &RecipientId, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Transfer>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query(&format!("/recipients/{}/transfers", token), &params)
+    }
+
+    pub fn list_transfers_with_paginator<'a>(client: &'a Client, token: &'a RecipientId, per_page: Option<u32>) -> Paginator<'a, Result<Transfer, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Recipient::list_transfers(client, token, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
 }
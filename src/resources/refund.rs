@@ -6,7 +6,7 @@ use crate::error::PinError;
 use crate::ids::{RefundId, ChargeId};
 use crate::params::{Page, Paginator, unpack_contained, paginate};
 use crate::resources::{
-    Currency
+    Money
 };
 use crate::build_map;
 
@@ -19,8 +19,9 @@ pub struct CreateRefund {
 pub struct Refund { 
     pub token: RefundId,
     pub success: Option<bool>,
-    pub amount: i64,
-    pub currency: Currency,
+    /// The `amount` and `currency` of the refund.
+    #[serde(flatten)]
+    pub money: Money,
     pub charge: ChargeId,
     #[serde(with = "time::serde::iso8601::option")]
     pub created_at: Option<OffsetDateTime>,
@@ -33,6 +34,12 @@ impl Refund {
         unpack_contained(client.post_form(&format!("/charges/{}/refunds", token), &params))
     }
 
+    /// Create a refund sending `key` as its idempotency key, so a replay of the same operation
+    /// carries the same key. See `Client::post_form_with_key`.
+    pub fn create_with_idempotency_key(client: &Client, token: &ChargeId, params: CreateRefund, key: &str) -> Response<Refund> {
+        unpack_contained(client.post_form_with_key(&format!("/charges/{}/refunds", token), &params, key))
+    }
+
     pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Refund>> {
         let page = page.map(|s| s.to_string());
         let per_page = per_page.map(|s| s.to_string());
@@ -43,7 +50,7 @@ impl Refund {
         client.get_query("/refunds", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Refund, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Refund, PinError>> {
         paginate(
             move |page, per_page| {
                 Refund::list(client, Some(page), Some(per_page))
@@ -3,28 +3,55 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::{Client, Response};
 use crate::error::PinError;
-use crate::ids::{PlanId, CustomerId, SubscriptionId, CardId};
+use crate::ids::{PlanId, CustomerId, SubscriptionId, CardId, PaymentSourceId};
 use crate::params::{unpack_contained, Page, Paginator, paginate};
-use crate::resources::{Currency};
+use crate::resources::{Money};
 use crate::build_map;
 
+/// Pin Payments starts a subscription when it is created, beginning with the plan's trial
+/// interval if it has one. The API takes no start date, so a later start is modelled with the
+/// plan's `trial_interval` and `trial_interval_unit`.
 #[derive(Debug, Default, Serialize)]
 pub struct CreateSubscription {
     pub plan_token: PlanId,
     pub customer_token: CustomerId,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_setup_fee: Option<bool>
+    pub include_setup_fee: Option<bool>,
+
+    /// The customer's card to bill. Defaults to the customer's primary card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_token: Option<CardId>,
+
+    /// A payment source to bill instead of a card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_source_token: Option<PaymentSourceId>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateSubscription {
+    /// The customer's card to bill from the next billing date.
+    pub card_token: CardId
 }
 
 
+def_string_enum! {
+    /// Where a subscription is in its lifecycle.
+    pub enum SubscriptionState {
+        Trial => "trial",
+        Active => "active",
+        Cancelling => "cancelling",
+        Cancelled => "cancelled",
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct Subscription {
     pub token: SubscriptionId,
     pub plan_token: PlanId,
     pub customer_token: CustomerId,
     pub card_token: CardId,
-    pub state: String,
+    pub state: SubscriptionState,
 
     #[serde(with = "time::serde::iso8601::option")]
     pub next_billing_date: Option<OffsetDateTime>,
@@ -42,22 +69,79 @@ pub struct Subscription {
     pub created_at: Option<OffsetDateTime>
 }
 
+def_string_enum! {
+    /// Whether a ledger entry adds to or takes from the subscription's balance.
+    pub enum LedgerEntryType {
+        Credit => "credit",
+        Debit => "debit",
+    }
+}
+
+def_string_enum! {
+    /// What a ledger entry was recorded for, as given by its `annotation`.
+    pub enum LedgerEntryKind {
+        ChargeCredit => "charge_credit",
+        IntervalAmount => "interval_amount",
+        SetupAmount => "setup_amount",
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct LedgerEntry {
-    pub r#type: String,
-    pub amount: i64,
-    pub currency: Currency,
-    pub annotation: String,
+    pub r#type: LedgerEntryType,
+    /// The `amount` and `currency` of the ledger entry.
+    #[serde(flatten)]
+    pub money: Money,
+    pub annotation: LedgerEntryKind,
 
     #[serde(with = "time::serde::iso8601::option")]
     pub created_at: Option<OffsetDateTime>
 }
 
+impl LedgerEntry {
+    /// The amount as it affects the balance: positive for credits and negative for debits.
+    /// `None` for entry types this crate does not know about, as their effect is unknown.
+    pub fn signed_amount(&self) -> Option<i64> {
+        match self.r#type {
+            LedgerEntryType::Credit => Some(self.money.amount),
+            LedgerEntryType::Debit => Some(-self.money.amount),
+            _ => None
+        }
+    }
+
+    /// The balance after applying all of `entries`, or `None` if any entry has an unknown type.
+    pub fn balance<'a>(entries: impl IntoIterator<Item = &'a LedgerEntry>) -> Option<i64> {
+        entries.into_iter().map(LedgerEntry::signed_amount).sum()
+    }
+
+    /// Pair each entry with the balance after it has been applied, in iteration order, or
+    /// `None` if any entry has an unknown type.
+    ///
+    /// Pin Payments lists the newest entries first, so reverse a listing to render a
+    /// statement from the oldest entry.
+    pub fn running_balances<'a>(entries: impl IntoIterator<Item = &'a LedgerEntry>) -> Option<Vec<(&'a LedgerEntry, i64)>> {
+        let mut balance = 0;
+        entries
+            .into_iter()
+            .map(|entry| {
+                balance += entry.signed_amount()?;
+                Some((entry, balance))
+            })
+            .collect()
+    }
+}
+
 impl Subscription {
     pub fn create(client: &Client, params: CreateSubscription) -> Response<Subscription> {
         unpack_contained(client.post_form("/subscriptions", &params))
     }
 
+    /// Create a subscription sending `key` as its idempotency key, so a replay of the same
+    /// operation carries the same key. See `Client::post_form_with_key`.
+    pub fn create_with_idempotency_key(client: &Client, params: CreateSubscription, key: &str) -> Response<Subscription> {
+        unpack_contained(client.post_form_with_key("/subscriptions", &params, key))
+    }
+
     pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Subscription>> {
         let page = page.map(|s| s.to_string());
         let per_page = per_page.map(|s| s.to_string());
@@ -68,7 +152,7 @@ impl Subscription {
         client.get_query("/subscriptions", &params)
     }
 
-    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<Result<Subscription, PinError>> {
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Subscription, PinError>> {
         paginate(
             move |page, per_page| {
                 Subscription::list(client, Some(page), Some(per_page))
@@ -81,6 +165,10 @@ impl Subscription {
         unpack_contained(client.get(&format!("/subscriptions/{}", token)))
     }
 
+    pub fn update(client: &Client, token: &SubscriptionId, params: UpdateSubscription) -> Response<Subscription> {
+        unpack_contained(client.put_form(&format!("/subscriptions/{}", token), &params))
+    }
+
     pub fn delete(client: &Client, token: &SubscriptionId) -> Response<Subscription> {
         unpack_contained(client.delete(&format!("/subscriptions/{}", token)))
     }
@@ -98,4 +186,13 @@ impl Subscription {
         ]);
         client.get_query(&format!("/subscriptions/{}/ledger", token), &params)
     }
+
+    pub fn list_ledger_entries_with_paginator<'a>(client: &'a Client, token: &'a SubscriptionId, per_page: Option<u32>) -> Paginator<'a, Result<LedgerEntry, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Subscription::list_ledger_entries(client, token, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
 }
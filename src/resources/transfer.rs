@@ -1,9 +1,9 @@
 use time::{OffsetDateTime};
 use serde::{Deserialize, Serialize};
 use crate::ids::{RecipientId, TransferId};
-use crate::params::{Page, unpack_contained, SortDirection};
-use crate::resources::{Currency, BankAccount};
-use crate::{Client, Response};
+use crate::params::{Page, Paginator, unpack_contained, paginate, SortDirection};
+use crate::resources::{Currency, BankAccount, Money};
+use crate::{Client, Response, PinError};
 use crate::build_map;
 
 #[derive(Clone, Debug, Default, Serialize)]
@@ -14,13 +14,24 @@ pub struct CreateTransfer<'a> {
     pub recipient: RecipientId
 }
 
+def_string_enum! {
+    /// Whether a transfer has been made to the recipient's bank account.
+    pub enum TransferStatus {
+        Pending => "pending",
+        Succeeded => "succeeded",
+        Paid => "paid",
+        Failed => "failed",
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize)]
 pub struct Transfer {
     pub token: TransferId,
-    pub status: String,
-    pub currency: Currency,
+    pub status: TransferStatus,
+    /// The `amount` and `currency` of the transfer.
+    #[serde(flatten)]
+    pub money: Money,
     pub description: String,
-    pub amount: i64,
     pub total_debits: i64,
     pub total_credits: i64,
 
@@ -37,9 +48,23 @@ pub struct Transfer {
     pub recipient: RecipientId
 }
 
+/// One of the charges, refunds, fees or adjustments making up a transfer.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct TransferLineItem {
+    pub r#type: String,
+    /// The `amount` and `currency` of the line item.
+    #[serde(flatten)]
+    pub money: Money,
+    pub object: String,
+    pub token: String,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
-pub enum SortByField {
+pub enum TransferSortByField {
     PaidAt
 }
 
@@ -52,7 +77,7 @@ pub struct TransferSearchParams<'a> {
     #[serde(with = "time::serde::iso8601::option", skip_serializing_if = "Option::is_none")]
     pub end_date: Option<OffsetDateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<SortByField>,
+    pub sort_by: Option<TransferSortByField>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub direction: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +88,13 @@ pub struct TransferSearchParams<'a> {
 
 impl Transfer {
     pub fn create(client: &Client, params: CreateTransfer<'_>) -> Response<Transfer> {
-        unpack_contained(client.post_form(&format!("/transfers"), &params))
+        unpack_contained(client.post_form("/transfers", &params))
+    }
+
+    /// Create a transfer sending `key` as its idempotency key, so a replay of the same operation
+    /// carries the same key. See `Client::post_form_with_key`.
+    pub fn create_with_idempotency_key(client: &Client, params: CreateTransfer<'_>, key: &str) -> Response<Transfer> {
+        unpack_contained(client.post_form_with_key("/transfers", &params, key))
     }
 
     pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Transfer>> {
@@ -77,10 +108,29 @@ impl Transfer {
     }
 
     pub fn search(client: &Client, search_params: &TransferSearchParams) -> Response<Page<Transfer>> {
-        client.get_query("/transfers/search", &search_params)
+        client.get_query("/transfers/search", search_params)
     }
 
     pub fn retrieve(client: &Client, token: &TransferId) -> Response<Transfer> {
         unpack_contained(client.get(&format!("/transfers/{}", token)))
     }
+
+    pub fn line_items(client: &Client, token: &TransferId, page: Option<u32>, per_page: Option<u32>) -> Response<Page<TransferLineItem>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query(&format!("/transfers/{}/line_items", token), &params)
+    }
+
+    pub fn line_items_with_paginator<'a>(client: &'a Client, token: &'a TransferId, per_page: Option<u32>) -> Paginator<'a, Result<TransferLineItem, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Transfer::line_items(client, token, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
 }
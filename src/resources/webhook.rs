@@ -0,0 +1,72 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize};
+
+use crate::client::{Client, Response};
+use crate::error::PinError;
+use crate::ids::{EventId, WebhookEndpointId, WebhookId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::build_map;
+
+/// A single delivery of an event to a webhook endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct Webhook {
+    pub token: WebhookId,
+    pub event: EventId,
+    pub url: String,
+    pub status: String,
+    pub number_of_attempts: u32,
+
+    #[serde(default)]
+    pub webhook_endpoint: Option<WebhookEndpointId>,
+
+    #[serde(default)]
+    pub response_code: Option<u16>,
+
+    #[serde(default)]
+    pub payload: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub error: Option<String>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub last_attempted_at: Option<OffsetDateTime>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub next_attempt_at: Option<OffsetDateTime>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub accepted_at: Option<OffsetDateTime>,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>
+}
+
+impl Webhook {
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<Webhook>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/webhooks", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<Webhook, PinError>> {
+        paginate(
+            move |page, per_page| {
+                Webhook::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &WebhookId) -> Response<Webhook> {
+        unpack_contained(client.get(&format!("/webhooks/{}", token)))
+    }
+
+    /// Request that a delivery is attempted again, e.g. after the endpoint has recovered.
+    pub fn replay(client: &Client, token: &WebhookId) -> Response<Webhook> {
+        unpack_contained(client.put(&format!("/webhooks/{}/replay", token)))
+    }
+}
@@ -0,0 +1,58 @@
+use time::{OffsetDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Response, StatusOnlyResponse};
+use crate::error::PinError;
+use crate::ids::{WebhookEndpointId};
+use crate::params::{unpack_contained, Page, Paginator, paginate};
+use crate::build_map;
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateWebhookEndpoint<'a> {
+    pub url: &'a str
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct WebhookEndpoint {
+    pub token: WebhookEndpointId,
+    pub url: String,
+
+    #[serde(with = "time::serde::iso8601::option")]
+    pub created_at: Option<OffsetDateTime>,
+
+    #[serde(default, with = "time::serde::iso8601::option")]
+    pub updated_at: Option<OffsetDateTime>
+}
+
+impl WebhookEndpoint {
+    pub fn create(client: &Client, params: CreateWebhookEndpoint<'_>) -> Response<WebhookEndpoint> {
+        unpack_contained(client.post_form("/webhook_endpoints", &params))
+    }
+
+    pub fn list(client: &Client, page: Option<u32>, per_page: Option<u32>) -> Response<Page<WebhookEndpoint>> {
+        let page = page.map(|s| s.to_string());
+        let per_page = per_page.map(|s| s.to_string());
+        let params = build_map([
+            ("page", page.as_deref()),
+            ("per_page", per_page.as_deref())
+        ]);
+        client.get_query("/webhook_endpoints", &params)
+    }
+
+    pub fn list_with_paginator(client: &Client, per_page: Option<u32>) -> Paginator<'_, Result<WebhookEndpoint, PinError>> {
+        paginate(
+            move |page, per_page| {
+                WebhookEndpoint::list(client, Some(page), Some(per_page))
+            },
+            per_page.unwrap_or(25)
+        )
+    }
+
+    pub fn retrieve(client: &Client, token: &WebhookEndpointId) -> Response<WebhookEndpoint> {
+        unpack_contained(client.get(&format!("/webhook_endpoints/{}", token)))
+    }
+
+    pub fn delete(client: &Client, token: &WebhookEndpointId) -> StatusOnlyResponse {
+        client.delete_status_only(&format!("/webhook_endpoints/{}", token))
+    }
+}
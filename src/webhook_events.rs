@@ -0,0 +1,75 @@
+use crate::client::{Client, Response};
+use crate::config::err;
+use crate::error::PinError;
+use crate::resources::Event;
+
+/// Helpers for turning the body of an inbound webhook request into a typed `Event`.
+///
+/// Pin Payments does not sign webhook requests, so a parsed payload only proves that it is
+/// well formed. Use `WebhookPayload::verify` to confirm it against the event held by Pin
+/// Payments before acting on it.
+pub struct WebhookPayload;
+
+impl WebhookPayload {
+    /// Parse a webhook request body into an `Event`.
+    ///
+    /// The `content-type` header, when present, must be JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PinError::InvalidWebhook` if the request is not JSON, or
+    /// `PinError::JSONSerialize` if the body is not a well formed event.
+    pub fn parse<K, V>(body: &[u8], headers: impl IntoIterator<Item = (K, V)>) -> Result<Event, PinError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let content_type = headers
+            .into_iter()
+            .find(|(name, _)| name.as_ref().eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_ref().to_ascii_lowercase());
+
+        if let Some(content_type) = content_type {
+            if !content_type.starts_with("application/json") {
+                return Err(PinError::InvalidWebhook(format!("unexpected content type {}", content_type)));
+            }
+        }
+
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Err(PinError::InvalidWebhook("empty body".to_string()));
+        }
+
+        let json_deserializer = &mut serde_json::Deserializer::from_slice(body);
+        serde_path_to_error::deserialize(json_deserializer).map_err(PinError::from)
+    }
+
+    /// Parse a webhook request body and confirm it by retrieving the event with the same token
+    /// from Pin Payments. The retrieved event is returned.
+    pub fn verify<K, V>(client: &Client, body: &[u8], headers: impl IntoIterator<Item = (K, V)>) -> Response<Event>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let event = match Self::parse(body, headers) {
+            Err(e) => return err(e),
+            Ok(ok) => ok,
+        };
+
+        let retrieved = Event::retrieve(client, &event.token);
+
+        Box::pin(async move {
+            let retrieved = retrieved.await?;
+
+            if retrieved.event_type() != event.event_type() {
+                return Err(PinError::InvalidWebhook(format!(
+                    "event {} is a {} event, not {}",
+                    event.token,
+                    retrieved.event_type(),
+                    event.event_type()
+                )));
+            }
+
+            Ok(retrieved)
+        })
+    }
+}
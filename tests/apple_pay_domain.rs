@@ -0,0 +1,134 @@
+use pinpayments::{ApplePayDomain, Client, CreateApplePayDomain};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+use http::StatusCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn create_apple_pay_domain_test() {
+    let json = common::get_fixture("tests/fixtures/create-apple-pay-domain.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/apple_pay/domains"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("domain_name", "shop.example.com")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let domain = ApplePayDomain::create(
+        &client,
+        CreateApplePayDomain {
+            domain_name: "shop.example.com"
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(domain.token, "apmd_yz6aQHVVNcvfhPrAo2ZtGg");
+    assert_eq!(domain.domain_name, "shop.example.com");
+    assert!(domain.verified);
+    assert_eq!(domain.created_at.unwrap(), datetime!(2024-01-08 3:12:45 UTC));
+}
+
+#[tokio::test]
+async fn list_apple_pay_domains_test() {
+    let json = common::get_fixture("tests/fixtures/get-apple-pay-domains.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/apple_pay/domains"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let domains = ApplePayDomain::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(domains.items.len(), 2);
+    assert_eq!(domains.items[0].token, "apmd_yz6aQHVVNcvfhPrAo2ZtGg");
+    assert_eq!(domains.items[1].domain_name, "store.example.org");
+    assert!(!domains.items[1].verified);
+}
+
+#[tokio::test]
+async fn delete_apple_pay_domain_test() {
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let domain_token = "apmd_yz6aQHVVNcvfhPrAo2ZtGg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("DELETE", format!("/1/apple_pay/domains/{}", domain_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(status_code(StatusCode::NO_CONTENT.into()))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let result = ApplePayDomain::delete(&client, &domain_token)
+        .await
+        .unwrap();
+
+    assert_eq!(result, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn apple_pay_domain_association_file_test() {
+    let json = common::get_fixture("tests/fixtures/get-apple-pay-domain-association.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/apple_pay/domain_association"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let file = ApplePayDomain::domain_association_file(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(file, "7B2270737049223A2236424641304133333244384431423933");
+}
@@ -0,0 +1,227 @@
+use pinpayments::{Authorisation, CardParams, Client, CreateAuthorisation, Currency, Money, PinError};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+use http::StatusCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn create_authorisation_test() {
+    let json = common::get_fixture("tests/fixtures/create-authorisation.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/authorisations"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("amount", "40000")))),
+                request::body(url_decoded(contains(("card[number]", "5520000000000000")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let authorisation = Authorisation::create(
+        &client,
+        CreateAuthorisation {
+            amount: 40000,
+            currency: Some(Currency::AUD),
+            description: "Two nights, deluxe king",
+            email: String::from("roland@pinpayments.com"),
+            ip_address: String::from("203.192.1.172"),
+            card: Some(
+                CardParams {
+                    number: "5520000000000000",
+                    expiry_month: "05",
+                    expiry_year: "2024",
+                    cvc: "123",
+                    name: "Roland Robot",
+                    address_line1: "42 Sevenoaks St",
+                    address_city: "Lathlain",
+                    address_postcode: "6454",
+                    address_state: "WA",
+                    address_country: "Australia",
+                    ..Default::default()
+                }
+            ),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(authorisation.token, "auth_Pq5mYzUGHWi3kYyH9PAvdw");
+    assert!(authorisation.success);
+    assert_eq!(authorisation.money.amount, 40000);
+    assert_eq!(authorisation.money.currency, Currency::AUD);
+    assert_eq!(authorisation.created_at.unwrap(), datetime!(2024-01-10 1:15:32 UTC));
+    assert_eq!(authorisation.captured_amount, 0);
+    assert!(!authorisation.expired);
+    assert!(!authorisation.voided);
+    assert_eq!(authorisation.card.token, "card_pIQJKMs93GsCc9vLSLevbw");
+}
+
+#[tokio::test]
+async fn list_authorisations_test() {
+    let json = common::get_fixture("tests/fixtures/get-authorisations.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/authorisations"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let authorisations = Authorisation::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(authorisations.items.len(), 2);
+    assert_eq!(authorisations.items[0].token, "auth_Pq5mYzUGHWi3kYyH9PAvdw");
+    assert_eq!(authorisations.items[1].token, "auth_7bNv2hM1xCrL6jXq5KdTfA");
+    assert_eq!(authorisations.items[1].captured_amount, 15000);
+}
+
+#[tokio::test]
+async fn retrieve_authorisation_test() {
+    let json = common::get_fixture("tests/fixtures/get-authorisation.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let authorisation_token = "auth_Pq5mYzUGHWi3kYyH9PAvdw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/authorisations/{}", authorisation_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let authorisation = Authorisation::retrieve(&client, &authorisation_token)
+        .await
+        .unwrap();
+
+    assert_eq!(authorisation.token, authorisation_token);
+    assert_eq!(authorisation.description, "Two nights, deluxe king");
+    assert_eq!(authorisation.metadata.unwrap()["BookingReference"], "HB-20240110-17");
+}
+
+#[tokio::test]
+async fn void_authorisation_test() {
+    let json = common::get_fixture("tests/fixtures/authorisation-void.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let authorisation_token = "auth_Pq5mYzUGHWi3kYyH9PAvdw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/authorisations/{}/void", authorisation_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let authorisation = Authorisation::void(&client, &authorisation_token)
+        .await
+        .unwrap();
+
+    assert_eq!(authorisation.token, authorisation_token);
+    assert!(authorisation.voided);
+}
+
+#[tokio::test]
+async fn capture_authorisation_test() {
+    let json = common::get_fixture("tests/fixtures/authorisation-capture.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let authorisation_token = "auth_Pq5mYzUGHWi3kYyH9PAvdw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", format!("/1/authorisations/{}/charges", authorisation_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("amount", "32500")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let authorised = Authorisation {
+        token: authorisation_token,
+        money: Money::new(40000, Currency::AUD),
+        ..Default::default()
+    };
+
+    let charge = Authorisation::capture(&client, &authorised, 32500)
+        .await
+        .unwrap();
+
+    assert_eq!(charge.token, "ch_Kx3WvRz1cYgQ8pLmNt0bHA");
+    assert_eq!(charge.money.amount, 32500);
+    assert!(charge.captured);
+    assert_eq!(charge.authorisation_token.unwrap(), "auth_Pq5mYzUGHWi3kYyH9PAvdw");
+}
+
+#[tokio::test]
+async fn capture_authorisation_exceeding_authorisation_test() {
+    let authorised = Authorisation {
+        token: "auth_Pq5mYzUGHWi3kYyH9PAvdw".parse().unwrap(),
+        money: Money::new(40000, Currency::AUD),
+        ..Default::default()
+    };
+
+    let client = Client::from_url("http://localhost:1/1/", "sk_test_12345");
+
+    let result = Authorisation::capture(&client, &authorised, 40001).await;
+    assert!(matches!(result, Err(PinError::InvalidRequest(_))));
+
+    let result = Authorisation::capture(&client, &authorised, 0).await;
+    assert!(matches!(result, Err(PinError::InvalidRequest(_))));
+}
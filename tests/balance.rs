@@ -1,4 +1,4 @@
-use pinpayments::{Client, Currency, Balance};
+use pinpayments::{Client, Currency, Balance, Money};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 
@@ -35,4 +35,9 @@ async fn get_balance_test() {
     assert_eq!(balance.available[0].currency, Currency::AUD);
     assert_eq!(balance.pending[0].amount, 1200);
     assert_eq!(balance.pending[0].currency, Currency::AUD);
+    assert_eq!(balance.pending[0].to_string(), "12.00 AUD");
+    assert_eq!(
+        balance.available[0].checked_add(balance.pending[0]),
+        Some(Money::new(1600, Currency::AUD))
+    );
 }
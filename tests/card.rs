@@ -1,4 +1,4 @@
-use pinpayments::{Client, CardParams, Card};
+use pinpayments::{Client, CardParams, Card, CardScheme};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 
@@ -48,7 +48,7 @@ async fn card_create_test() {
     .unwrap();
 
     assert_eq!(card.token, "card_pIQJKMs93GsCc9vLSLevbw");
-    assert_eq!(card.scheme, "master");
+    assert_eq!(card.scheme, CardScheme::Master);
     assert_eq!(card.display_number, "XXXX-XXXX-XXXX-0000");
     assert_eq!(card.issuing_country, "US");
     assert_eq!(card.expiry_month, 5);
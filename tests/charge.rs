@@ -1,4 +1,4 @@
-use pinpayments::{Client, Currency, CreateCharge, Charge, CardParams};
+use pinpayments::{Client, Currency, CreateCharge, Charge, CardParams, PinError, CardScheme, Money};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -59,9 +59,9 @@ async fn charge_create_test() {
     .unwrap();
 
     assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(charge.success, true);
-    assert_eq!(charge.amount, 400);
-    assert_eq!(charge.currency, Currency::AUD);
+    assert!(charge.success);
+    assert_eq!(charge.money.amount, 400);
+    assert_eq!(charge.money.currency, Currency::AUD);
     assert_eq!(charge.description, "test charge");
     assert_eq!(charge.email, "roland@pinpayments.com");
     assert_eq!(charge.ip_address, "203.192.1.172");
@@ -71,11 +71,12 @@ async fn charge_create_test() {
     assert_eq!(charge.amount_refunded, 0);
     assert_eq!(charge.total_fees.unwrap(), 42);
     assert_eq!(charge.merchant_entitlement.unwrap(), 358);
-    assert_eq!(charge.refund_pending, false);
+    assert!(!charge.refund_pending);
+    assert_eq!(charge.money.to_string(), "4.00 AUD");
 
 
     assert_eq!(charge.card.token, "card_pIQJKMs93GsCc9vLSLevbw");
-    assert_eq!(charge.card.scheme, "master");
+    assert_eq!(charge.card.scheme, CardScheme::Master);
     assert_eq!(charge.card.display_number, "XXXX-XXXX-XXXX-0000");
     assert_eq!(charge.card.issuing_country, "US");
     assert_eq!(charge.card.expiry_month, 5);
@@ -83,6 +84,71 @@ async fn charge_create_test() {
     assert_eq!(charge.card.name, "Roland Robot");
 }
 
+#[tokio::test]
+async fn charge_validate_test() {
+    let params = CreateCharge {
+        amount: 400,
+        currency: Some(Currency::VND),
+        description: "test charge",
+        email: String::from("roland@pinpayments.com"),
+        ip_address: String::from("203.192.1.172"),
+        ..Default::default()
+    };
+    assert!(matches!(params.validate(), Err(PinError::InvalidRequest(_))));
+
+    let params = CreateCharge {
+        amount: 0,
+        currency: Some(Currency::AUD),
+        ..Default::default()
+    };
+    assert!(matches!(params.validate(), Err(PinError::InvalidRequest(_))));
+
+    let params = CreateCharge {
+        amount: 400,
+        currency: Some(Currency::AUD),
+        ..Default::default()
+    };
+    assert!(params.validate().is_ok());
+}
+
+#[tokio::test]
+async fn charge_create_with_idempotency_key_test() {
+    let json = common::get_fixture("tests/fixtures/create-charge.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/charges"),
+                request::headers(contains(("idempotency-key", "order-42"))),
+            ]).
+            respond_with(
+                status_code(201)
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let charge = Charge::create_with_idempotency_key(
+        &client,
+        CreateCharge {
+            amount: 400,
+            currency: Some(Currency::AUD),
+            description: "test charge",
+            email: String::from("roland@pinpayments.com"),
+            ip_address: String::from("203.192.1.172"),
+            ..Default::default()
+        },
+        "order-42",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
+}
+
 #[tokio::test]
 async fn get_charge_test() {
     let json = common::get_fixture("tests/fixtures/get-charge.json");
@@ -136,7 +202,7 @@ async fn charge_void_test() {
 
     assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
     assert_eq!(charge.status_message, "Authorisation Voided");
-    assert_eq!(charge.authorisation_voided, true);
+    assert!(charge.authorisation_voided);
 }
 
 #[tokio::test]
@@ -165,12 +231,63 @@ async fn charge_capture_test() {
 
     assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
     assert_eq!(charge.status_message, "Success");
-    assert_eq!(charge.captured, true);
+    assert!(charge.captured);
     if let Some(cap) = charge.captured_at {
         assert_eq!(cap, datetime!(2012-06-20 3:10:49 UTC));
     }
 }
 
+#[tokio::test]
+async fn charge_capture_amount_test() {
+    let json = common::get_fixture("tests/fixtures/charge-capture-partial.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", "/1/charges/ch_lfUYEBK14zotCTykezJkfg/capture"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("amount", "250")))),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let authorised = Charge {
+        token: "ch_lfUYEBK14zotCTykezJkfg".parse().unwrap(),
+        money: Money::new(400, Currency::AUD),
+        ..Default::default()
+    };
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+    let charge = Charge::capture_amount(&client, &authorised, 250).await.unwrap();
+
+    assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
+    assert_eq!(charge.money.amount, 250);
+    assert!(charge.captured);
+}
+
+#[tokio::test]
+async fn charge_capture_amount_exceeding_charge_test() {
+    let authorised = Charge {
+        token: "ch_lfUYEBK14zotCTykezJkfg".parse().unwrap(),
+        money: Money::new(400, Currency::AUD),
+        ..Default::default()
+    };
+
+    let client = Client::from_url("http://localhost:1/1/", "sk_test_12345");
+
+    let result = Charge::capture_amount(&client, &authorised, 401).await;
+    assert!(matches!(result, Err(PinError::InvalidRequest(_))));
+
+    let result = Charge::capture_amount(&client, &authorised, 0).await;
+    assert!(matches!(result, Err(PinError::InvalidRequest(_))));
+}
+
 #[tokio::test]
 async fn charge_list_test() {
     let json = common::get_fixture("tests/fixtures/get-charges.json");
@@ -222,6 +339,6 @@ async fn charge_verify_test() {
     let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
     let charge = Charge::verify(&client, &session_token).await.unwrap();
 
-    assert_eq!(charge.success, true);
+    assert!(charge.success);
     assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
 }
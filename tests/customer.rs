@@ -1,4 +1,4 @@
-use pinpayments::{Client, Currency, CreateCustomer, Customer, CardParams};
+use pinpayments::{Client, Currency, CreateCustomer, Customer, CardParams, UpdateCustomer, CardScheme, SubscriptionState};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -145,6 +145,50 @@ async fn customer_list_test() {
     assert_eq!(customers.items[0].created_at.unwrap(), datetime!(2012-06-22 6:27:33 UTC));
 }
 
+#[tokio::test]
+async fn customer_update_test() {
+    let json = common::get_fixture("tests/fixtures/update-customer.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let customer_token = "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/customers/{}", customer_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("email", "roland.robot@pinpayments.com")))),
+                request::body(url_decoded(contains(("primary_card_token", "card_ZFThCjFJ0QtwhZi0jkqVnw")))),
+                request::body(url_decoded(not(contains(key("first_name"))))),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let customer = Customer::update(
+        &client,
+        &customer_token,
+        UpdateCustomer {
+            email: Some("roland.robot@pinpayments.com"),
+            primary_card_token: Some("card_ZFThCjFJ0QtwhZi0jkqVnw".parse().unwrap()),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(customer.token, "cus_XZg1ULpWaROQCOT5PdwLkQ");
+    assert_eq!(customer.email, "roland.robot@pinpayments.com");
+    assert_eq!(customer.card.token, "card_ZFThCjFJ0QtwhZi0jkqVnw");
+    assert_eq!(customer.card.display_number, "XXXX-XXXX-XXXX-0001");
+}
+
 #[tokio::test]
 async fn customer_delete_test() {
     let auth = BasicAuth::new("sk_test_12345", "");
@@ -202,9 +246,9 @@ async fn customer_list_charges_test() {
         .unwrap();
 
     assert_eq!(charges.items[0].token, "ch_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(charges.items[0].success, true);
-    assert_eq!(charges.items[0].amount, 400);
-    assert_eq!(charges.items[0].currency, Currency::AUD);
+    assert!(charges.items[0].success);
+    assert_eq!(charges.items[0].money.amount, 400);
+    assert_eq!(charges.items[0].money.currency, Currency::AUD);
     assert_eq!(charges.items[0].description, "test charge");
     assert_eq!(charges.items[0].email, "roland@pinpayments.com");
     assert_eq!(charges.items[0].ip_address, "203.192.1.172");
@@ -238,9 +282,9 @@ async fn customer_list_cards_test() {
         .unwrap();
 
     assert_eq!(cards.items[0].token, "card_nytGw7koRg23EEp9NTmz9w");
-    assert_eq!(cards.items[0].scheme, "master");
+    assert_eq!(cards.items[0].scheme, CardScheme::Master);
     assert_eq!(cards.items[1].token, "card_ZFThCjFi7wCNkopytxQVKA");
-    assert_eq!(cards.items[1].scheme, "master");
+    assert_eq!(cards.items[1].scheme, CardScheme::Master);
     assert_eq!(cards.pagination.count, 2);
 }
 
@@ -291,5 +335,72 @@ async fn customer_create_card_test() {
     .unwrap();
 
     assert_eq!(card.token, "card_ZFThCjFi7wCNkopytxQVKA");
-    assert_eq!(card.scheme, "master");
+    assert_eq!(card.scheme, CardScheme::Master);
+}
+
+#[tokio::test]
+async fn customer_list_subscriptions_test() {
+    let json = common::get_fixture("tests/fixtures/get-subscriptions.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let customer_token = "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/customers/{}/subscriptions", customer_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let subscriptions = Customer::list_subscriptions(&client, &customer_token, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(subscriptions.items.len(), 1);
+    assert_eq!(subscriptions.items[0].token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
+    assert_eq!(subscriptions.items[0].customer_token, "cus_XZg1ULpWaROQCOT5PdwLkQ");
+    assert_eq!(subscriptions.items[0].plan_token, "plan_ZyDee4HNeUHFHC4SpM2idg");
+    assert_eq!(subscriptions.items[0].state, SubscriptionState::Active);
+}
+
+#[tokio::test]
+async fn customer_delete_subscription_test() {
+    let json = common::get_fixture("tests/fixtures/delete-subscription.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let customer_token = "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap();
+    let subscription_token = "sub_bZWXhTzHooKpk9FZjQfzqQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("DELETE", format!("/1/customers/{}/subscriptions/{}", customer_token, subscription_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let subscription = Customer::delete_subscription(&client, &customer_token, &subscription_token)
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
+    assert_eq!(subscription.state, SubscriptionState::Cancelled);
+    assert_eq!(subscription.cancelled_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
 }
@@ -0,0 +1,107 @@
+use pinpayments::{Client, Currency, Deposit};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+
+pub mod common;
+
+#[tokio::test]
+async fn list_deposits_test() {
+    let json = common::get_fixture("tests/fixtures/get-deposits.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/deposits"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let deposits = Deposit::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(deposits.items.len(), 2);
+    assert_eq!(deposits.items[0].token, "dpo_4Cr8yNmzHm3tIuJ0SkpQWw");
+    assert_eq!(deposits.items[0].status, "paid");
+    assert_eq!(deposits.items[0].bank_account.as_ref().unwrap().token, "ba_nytGw7koRg23EEp9NTmz9w");
+    assert_eq!(deposits.items[1].token, "dpo_kM0oxCWIbnfF6ahjHkeIjA");
+    assert_eq!(deposits.items[1].status, "pending");
+    assert!(deposits.items[1].paid_at.is_none());
+    assert!(deposits.items[1].bank_account.is_none());
+}
+
+#[tokio::test]
+async fn retrieve_deposit_test() {
+    let json = common::get_fixture("tests/fixtures/get-deposit.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let deposit_token = "dpo_4Cr8yNmzHm3tIuJ0SkpQWw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/deposits/{}", deposit_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let deposit = Deposit::retrieve(&client, &deposit_token).await.unwrap();
+
+    assert_eq!(deposit.token, "dpo_4Cr8yNmzHm3tIuJ0SkpQWw");
+    assert_eq!(deposit.money.currency, Currency::AUD);
+    assert_eq!(deposit.money.amount, 400);
+    assert_eq!(deposit.total_debits, 200);
+    assert_eq!(deposit.total_credits, 600);
+    assert_eq!(deposit.reference.unwrap(), "PIN PAYMENTS 4CR8YN");
+    assert_eq!(deposit.line_items_count, 3);
+    assert_eq!(deposit.created_at.unwrap(), datetime!(2023-12-28 0:00:00 UTC));
+    assert_eq!(deposit.paid_at.unwrap(), datetime!(2023-12-29 0:00:00 UTC));
+}
+
+#[tokio::test]
+async fn deposit_line_items_test() {
+    let json = common::get_fixture("tests/fixtures/get-transfer-line-items.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let deposit_token = "dpo_4Cr8yNmzHm3tIuJ0SkpQWw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/deposits/{}/line_items", deposit_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let line_items = Deposit::line_items(&client, &deposit_token, None, None).await.unwrap();
+
+    assert_eq!(line_items.items.len(), 3);
+    assert_eq!(line_items.items[0].token, "ch_lfUYEBK14zotCTykezJkfg");
+    assert_eq!(line_items.items[1].object, "refund");
+}
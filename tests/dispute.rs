@@ -1,4 +1,4 @@
-use pinpayments::{Client, Currency, Dispute, DisputeSearchParams};
+use pinpayments::{Client, Currency, Dispute, DisputeSearchParams, UpdateDisputeEvidence, UpdateDisputeEvidenceItem, DisputeCategory, DisputeStatus};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -30,10 +30,10 @@ async fn list_dispute_test() {
     let disputes = Dispute::list(&client, None, None).await.unwrap();
 
     assert_eq!(disputes.items[0].token, "dis_JRs6Xgk4jMyF33yGijQ7Nw");
-    assert_eq!(disputes.items[0].category, "general");
-    assert_eq!(disputes.items[0].status, "evidence_required");
-    assert_eq!(disputes.items[0].amount, 100);
-    assert_eq!(disputes.items[0].currency, Currency::AUD);
+    assert_eq!(disputes.items[0].category, DisputeCategory::General);
+    assert_eq!(disputes.items[0].status, DisputeStatus::EvidenceRequired);
+    assert_eq!(disputes.items[0].money.amount, 100);
+    assert_eq!(disputes.items[0].money.currency, Currency::AUD);
     assert_eq!(disputes.items[0].evidence_required_by.unwrap(), datetime!(2023-10-15 00:00:00 UTC));
     assert_eq!(disputes.items[0].relevant_evidence, vec![ 
         "proof_of_delivery_or_service",
@@ -81,10 +81,10 @@ async fn search_dispute_test() {
     .unwrap();
 
     assert_eq!(disputes.items[0].token, "dis_JRs6Xgk4jMyF33yGijQ7Nw");
-    assert_eq!(disputes.items[0].category, "general");
-    assert_eq!(disputes.items[0].status, "evidence_required");
-    assert_eq!(disputes.items[0].amount, 100);
-    assert_eq!(disputes.items[0].currency, Currency::AUD);
+    assert_eq!(disputes.items[0].category, DisputeCategory::General);
+    assert_eq!(disputes.items[0].status, DisputeStatus::EvidenceRequired);
+    assert_eq!(disputes.items[0].money.amount, 100);
+    assert_eq!(disputes.items[0].money.currency, Currency::AUD);
     assert_eq!(disputes.items[0].evidence_required_by.unwrap(), datetime!(2023-10-15 00:00:00 UTC));
     assert_eq!(disputes.items[0].relevant_evidence, vec![ 
         "proof_of_delivery_or_service",
@@ -126,10 +126,10 @@ async fn retrieve_dispute_test() {
     let dispute = Dispute::retrieve(&client, &dispute_token).await.unwrap();
 
     assert_eq!(dispute.token, "dis_JRs6Xgk4jMyF33yGijQ7Nw");
-    assert_eq!(dispute.category, "general");
-    assert_eq!(dispute.status, "evidence_required");
-    assert_eq!(dispute.amount, 100);
-    assert_eq!(dispute.currency, Currency::AUD);
+    assert_eq!(dispute.category, DisputeCategory::General);
+    assert_eq!(dispute.status, DisputeStatus::EvidenceRequired);
+    assert_eq!(dispute.money.amount, 100);
+    assert_eq!(dispute.money.currency, Currency::AUD);
     assert_eq!(dispute.evidence_required_by.unwrap(), datetime!(2023-10-15 00:00:00 UTC));
     assert_eq!(dispute.relevant_evidence, vec![ 
         "proof_of_delivery_or_service",
@@ -196,3 +196,113 @@ async fn accept_dispute_test() {
 
     assert_eq!(result, StatusCode::OK);
 }
+
+#[tokio::test]
+async fn retrieve_dispute_evidence_test() {
+    let json = common::get_fixture("tests/fixtures/get-dispute-evidence.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let dispute_token = "dis_JRs6Xgk4jMyF33yGijQ7Nw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/disputes/{}/evidence", dispute_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let evidence = Dispute::evidence(&client, &dispute_token).await.unwrap();
+
+    let delivery = evidence.proof_of_delivery_or_service.unwrap();
+    assert_eq!(delivery.details.unwrap(), "Signed for at the front desk by R. Robot.");
+    assert_eq!(delivery.files, vec!["file_lfUYEBK14zotCTykezJkfg"]);
+    assert!(evidence.invoice_or_receipt.is_none());
+    assert!(evidence.customer_communication.unwrap().files.is_empty());
+}
+
+#[tokio::test]
+async fn update_dispute_evidence_test() {
+    let json = common::get_fixture("tests/fixtures/update-dispute-evidence.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let dispute_token = "dis_JRs6Xgk4jMyF33yGijQ7Nw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/disputes/{}/evidence", dispute_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("invoice_or_receipt[details]", "Invoice 123456 for the disputed charge.")))),
+                request::body(url_decoded(contains(("invoice_or_receipt[files][0]", "file_8wBZ2mJxTq3cVnKpLd7RYg")))),
+                request::body(url_decoded(not(contains(key("proof_of_delivery_or_service[details]"))))),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let evidence = Dispute::update_evidence(
+        &client,
+        &dispute_token,
+        UpdateDisputeEvidence {
+            invoice_or_receipt: Some(
+                UpdateDisputeEvidenceItem {
+                    details: Some("Invoice 123456 for the disputed charge."),
+                    files: vec!["file_8wBZ2mJxTq3cVnKpLd7RYg".parse().unwrap()]
+                }
+            ),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    let invoice = evidence.invoice_or_receipt.unwrap();
+    assert_eq!(invoice.files, vec!["file_8wBZ2mJxTq3cVnKpLd7RYg"]);
+    assert!(evidence.proof_of_delivery_or_service.is_some());
+}
+
+#[tokio::test]
+async fn dispute_activity_test() {
+    let json = common::get_fixture("tests/fixtures/get-dispute-activity.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let dispute_token = "dis_JRs6Xgk4jMyF33yGijQ7Nw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/disputes/{}/activity", dispute_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let activity = Dispute::activity(&client, &dispute_token).await.unwrap();
+
+    assert_eq!(activity.len(), 2);
+    assert_eq!(activity[0].action, "dispute_opened");
+    assert_eq!(activity[0].created_at.unwrap(), datetime!(2023-09-25 9:23:58 UTC));
+    assert!(activity[1].description.is_none());
+}
@@ -0,0 +1,87 @@
+use pinpayments::{
+    CardScheme, Dispute, DisputeCategory, DisputeStatus, File, FilePurpose, Subscription,
+    SubscriptionState, Transfer, TransferStatus
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+pub mod common;
+
+fn field(fixture: &str, pointer: &str) -> Value {
+    common::get_fixture(fixture)
+        .pointer(pointer)
+        .unwrap_or_else(|| panic!("{} missing from {}", pointer, fixture))
+        .clone()
+}
+
+fn round_trip<T: DeserializeOwned + Serialize>(value: Value) -> T {
+    let typed: T = serde_json::from_value(value.clone()).expect("failed to deserialize");
+    assert_eq!(serde_json::to_value(&typed).expect("failed to serialize"), value);
+    typed
+}
+
+#[test]
+fn subscription_state_round_trip() {
+    let state: SubscriptionState = round_trip(field("tests/fixtures/get-subscription.json", "/response/state"));
+    assert_eq!(state, SubscriptionState::Active);
+
+    let state: SubscriptionState = round_trip(field("tests/fixtures/delete-subscription.json", "/response/state"));
+    assert_eq!(state, SubscriptionState::Cancelled);
+}
+
+#[test]
+fn dispute_status_and_category_round_trip() {
+    let status: DisputeStatus = round_trip(field("tests/fixtures/get-dispute.json", "/response/status"));
+    assert_eq!(status, DisputeStatus::EvidenceRequired);
+
+    let category: DisputeCategory = round_trip(field("tests/fixtures/get-dispute.json", "/response/category"));
+    assert_eq!(category, DisputeCategory::General);
+}
+
+#[test]
+fn transfer_status_round_trip() {
+    let status: TransferStatus = round_trip(field("tests/fixtures/get-transfer.json", "/response/status"));
+    assert_eq!(status, TransferStatus::Succeeded);
+}
+
+#[test]
+fn file_purpose_round_trip() {
+    let purpose: FilePurpose = round_trip(field("tests/fixtures/get-file.json", "/response/purpose"));
+    assert_eq!(purpose, FilePurpose::DisputeEvidence);
+}
+
+#[test]
+fn card_scheme_round_trip() {
+    let scheme: CardScheme = round_trip(field("tests/fixtures/create-card.json", "/response/scheme"));
+    assert_eq!(scheme, CardScheme::Master);
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    let state: SubscriptionState = round_trip(Value::from("past_due"));
+    assert_eq!(state, SubscriptionState::Other(String::from("past_due")));
+    assert_eq!(state.to_string(), "past_due");
+
+    let scheme: CardScheme = round_trip(Value::from("unionpay"));
+    assert_eq!(scheme.as_str(), "unionpay");
+}
+
+#[test]
+fn resources_deserialize_typed_fields() {
+    let subscription: Subscription = serde_json::from_value(field("tests/fixtures/get-subscription.json", "/response"))
+        .expect("failed to deserialize subscription");
+    assert_eq!(subscription.state, SubscriptionState::Active);
+
+    let dispute: Dispute = serde_json::from_value(field("tests/fixtures/get-dispute.json", "/response"))
+        .expect("failed to deserialize dispute");
+    assert_eq!(dispute.status, DisputeStatus::EvidenceRequired);
+    assert_eq!(dispute.charge.card.scheme, CardScheme::Master);
+
+    let transfer: Transfer = serde_json::from_value(field("tests/fixtures/get-transfer.json", "/response"))
+        .expect("failed to deserialize transfer");
+    assert_eq!(transfer.status, TransferStatus::Succeeded);
+
+    let file: File = serde_json::from_value(field("tests/fixtures/get-file.json", "/response"))
+        .expect("failed to deserialize file");
+    assert_eq!(file.purpose, FilePurpose::DisputeEvidence);
+}
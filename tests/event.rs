@@ -0,0 +1,108 @@
+use pinpayments::{Client, Currency, Event, EventData};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+
+pub mod common;
+
+#[tokio::test]
+async fn list_events_test() {
+    let json = common::get_fixture("tests/fixtures/get-events.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/events"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let events = Event::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(events.items.len(), 4);
+
+    assert_eq!(events.items[0].token, "evt_9eW6hFzDhQFpWKAVTbgCDw");
+    assert_eq!(events.items[0].event_type(), "refund.created");
+    match &events.items[0].data {
+        EventData::RefundCreated(refund) => {
+            assert_eq!(refund.token, "rf_ERCQy--Ay6o-NKGiUVcKKA");
+            assert_eq!(refund.money.amount, 400);
+        },
+        other => panic!("Expected RefundCreated {:?}", other),
+    }
+
+    match &events.items[1].data {
+        EventData::DisputeEvidenceRequired(dispute) => {
+            assert_eq!(dispute.token, "dis_JRs6Xgk4jMyF33yGijQ7Nw");
+            assert_eq!(dispute.charge.token, "ch_yJM0U_NaAsyY2A7Se3IFYQ");
+        },
+        other => panic!("Expected DisputeEvidenceRequired {:?}", other),
+    }
+
+    match &events.items[2].data {
+        EventData::TransferPaid(transfer) => {
+            assert_eq!(transfer.token, "tfer_lfUYEBK14zotCTykezJkfg");
+            assert_eq!(transfer.money.currency, Currency::AUD);
+        },
+        other => panic!("Expected TransferPaid {:?}", other),
+    }
+
+    assert_eq!(events.items[3].event_type(), "deposit.created");
+    match &events.items[3].data {
+        EventData::Unknown { r#type, data } => {
+            assert_eq!(r#type, "deposit.created");
+            assert_eq!(data["token"], "dpo_4Cr8yNmzHm3tIuJ0SkpQWw");
+        },
+        other => panic!("Expected Unknown {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn retrieve_event_test() {
+    let json = common::get_fixture("tests/fixtures/get-event.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let event_token = "evt_YZvAv1AjJ6N6FjPnm8LdbQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/events/{}", event_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let event = Event::retrieve(&client, &event_token).await.unwrap();
+
+    assert_eq!(event.token, "evt_YZvAv1AjJ6N6FjPnm8LdbQ");
+    assert_eq!(event.event_type(), "charge.succeeded");
+    assert_eq!(event.created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
+
+    match event.data {
+        EventData::ChargeSucceeded(charge) => {
+            assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
+            assert_eq!(charge.money.amount, 400);
+            assert_eq!(charge.card.token, "card_pIQJKMs93GsCc9vLSLevbw");
+        },
+        other => panic!("Expected ChargeSucceeded {:?}", other),
+    }
+}
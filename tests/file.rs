@@ -1,4 +1,4 @@
-use pinpayments::{Client, File};
+use pinpayments::{Client, File, FilePurpose};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -6,6 +6,73 @@ use http::StatusCode;
 
 pub mod common;
 
+fn expect_upload(server: &httptest::Server, filename: &str, mime_type: &str) {
+    let auth = BasicAuth::new("sk_test_12345", "");
+    let json = common::get_fixture("tests/fixtures/create-file.json");
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/files"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::headers(contains(("content-type", matches("^multipart/form-data; boundary=")))),
+                request::body(matches("name=\"purpose\"\r\n\r\ndispute_evidence\r\n")),
+                request::body(matches(format!("name=\"file\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n%PDF-1.4\r\n", filename, mime_type))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+}
+
+#[tokio::test]
+async fn create_file_test() {
+    let server = common::SERVER_POOL.get_server();
+    expect_upload(&server, "receipt.pdf", "application/pdf");
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let file = File::create(&client, FilePurpose::DisputeEvidence, "receipt.pdf", b"%PDF-1.4").await.unwrap();
+
+    assert_eq!(file.token, "file_8wBZ2mJxTq3cVnKpLd7RYg");
+    assert_eq!(file.original_filename, "receipt.pdf");
+    assert_eq!(file.purpose, FilePurpose::DisputeEvidence);
+    assert_eq!(file.mime_type, "application/pdf");
+    assert_eq!(file.uploaded_at.unwrap(), datetime!(2024-01-15 3:21:07 UTC));
+}
+
+#[tokio::test]
+async fn create_file_from_path_test() {
+    let server = common::SERVER_POOL.get_server();
+    expect_upload(&server, "upload-from-path.pdf", "application/pdf");
+
+    let path = std::env::temp_dir().join("upload-from-path.pdf");
+    std::fs::write(&path, b"%PDF-1.4").unwrap();
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let file = File::create_from_path(&client, FilePurpose::DisputeEvidence, &path).await;
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(file.unwrap().token, "file_8wBZ2mJxTq3cVnKpLd7RYg");
+}
+
+#[tokio::test]
+async fn create_file_from_reader_test() {
+    let server = common::SERVER_POOL.get_server();
+    expect_upload(&server, "scan", "application/octet-stream");
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let reader = futures::io::Cursor::new(b"%PDF-1.4".to_vec());
+    let file = File::create_from_reader(&client, FilePurpose::DisputeEvidence, "scan", reader).await.unwrap();
+
+    assert_eq!(file.token, "file_8wBZ2mJxTq3cVnKpLd7RYg");
+}
+
 #[tokio::test]
 async fn retrieve_file_test() {
     let json = common::get_fixture("tests/fixtures/get-file.json");
@@ -35,7 +102,7 @@ async fn retrieve_file_test() {
     assert_eq!(file.original_filename, "cat.jpeg");
     assert_eq!(file.presigned_url, "https://pin-gateway-api-files-production-sandbox.s3.ap-southeast-2.amazonaws.com/sample.jpg");
     assert_eq!(file.presigned_url_expires_at.unwrap(), datetime!(2023-09-19 7:49:26 UTC));
-    assert_eq!(file.purpose, "dispute_evidence");
+    assert_eq!(file.purpose, FilePurpose::DisputeEvidence);
     assert_eq!(file.size, 8060);
     assert_eq!(file.mime_type, "image/jpeg");
     assert_eq!(file.uploaded_at.unwrap(), datetime!(2023-09-19 6:47:54 UTC));
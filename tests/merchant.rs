@@ -0,0 +1,202 @@
+use pinpayments::{
+    Client, CreateBankAccount, CreateMerchant, Merchant, MerchantBusiness, MerchantContact,
+    MerchantDirector, MerchantEntity
+};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+use http::StatusCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn create_merchant_test() {
+    let json = common::get_fixture("tests/fixtures/create-merchant.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/merchants"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("contact[email]", "roland@pinpayments.com")))),
+                request::body(url_decoded(contains(("entity[full_legal_name]", "Roland Robot's Fine Robotics Pty Ltd")))),
+                request::body(url_decoded(contains(("bank_account[bsb]", "123456")))),
+                request::body(url_decoded(contains(("business[sells_physical_goods]", "true")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let merchant = Merchant::create(
+        &client,
+        CreateMerchant {
+            contact: MerchantContact {
+                first_name: "Roland",
+                last_name: "Robot",
+                phone_number: "02 9876 5432",
+                email: "roland@pinpayments.com",
+                password: "new-user-password"
+            },
+            entity: MerchantEntity {
+                business_registration_number: "11223491505",
+                full_legal_name: "Roland Robot's Fine Robotics Pty Ltd",
+                address_line_1: "58 Durham Rd",
+                address_locality: "Kilsyth",
+                address_region: "VIC",
+                address_postal_code: "3137",
+                ..Default::default()
+            },
+            business: MerchantBusiness {
+                trading_name: "Roland Robot's Fine Robotics",
+                description: "We sell robots",
+                typical_product_price: 1000,
+                transactions_per_month: 100,
+                annual_transaction_volume: 1000000,
+                sells_physical_goods: true,
+                average_delivery_days: 14,
+                url: Some("https://rrfr.com.au")
+            },
+            bank_account: CreateBankAccount {
+                name: "RRFR",
+                bsb: "123456",
+                number: "987654321"
+            },
+            director: MerchantDirector {
+                full_name: "Roland Robot",
+                contact_number: "0412 345 678",
+                date_of_birth: "1984-06-12"
+            },
+            notes: None
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(merchant.token, "mrch_roZOG7c8IY4_8ak2gS8Yrw");
+    assert_eq!(merchant.email, "roland@pinpayments.com");
+    assert_eq!(merchant.business_name, "Roland Robot's Fine Robotics");
+    assert_eq!(merchant.status, "pending");
+    assert_eq!(merchant.created_at.unwrap(), datetime!(2023-12-29 4:30:00 UTC));
+}
+
+#[tokio::test]
+async fn list_merchants_test() {
+    let json = common::get_fixture("tests/fixtures/get-merchants.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/merchants"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let merchants = Merchant::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(merchants.items.len(), 1);
+    assert_eq!(merchants.items[0].token, "mrch_roZOG7c8IY4_8ak2gS8Yrw");
+    assert_eq!(merchants.items[0].status, "approved");
+}
+
+#[tokio::test]
+async fn retrieve_merchant_test() {
+    let json = common::get_fixture("tests/fixtures/get-merchant.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let merchant_token = "mrch_roZOG7c8IY4_8ak2gS8Yrw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/merchants/{}", merchant_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let merchant = Merchant::retrieve(&client, &merchant_token).await.unwrap();
+
+    assert_eq!(merchant.token, "mrch_roZOG7c8IY4_8ak2gS8Yrw");
+    assert_eq!(merchant.status, "approved");
+    assert_eq!(merchant.updated_at.unwrap(), datetime!(2023-12-30 1:15:00 UTC));
+}
+
+#[tokio::test]
+async fn merchant_default_settings_test() {
+    let json = common::get_fixture("tests/fixtures/get-merchant-default-settings.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/merchants/default_settings"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let settings = Merchant::default_settings(&client).await.unwrap();
+
+    assert_eq!(settings.settings["transfer_schedule"], "daily");
+    assert_eq!(settings.settings["fees"]["fixed"], 30);
+}
+
+#[tokio::test]
+async fn on_behalf_of_merchant_test() {
+    let json = common::get_fixture("tests/fixtures/get-merchant.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let merchant_token = "mrch_roZOG7c8IY4_8ak2gS8Yrw".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/merchants/{}", merchant_token)),
+                request::headers(contains(("x-merchant-token", "mrch_roZOG7c8IY4_8ak2gS8Yrw"))),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345")
+        .with_merchant("mrch_roZOG7c8IY4_8ak2gS8Yrw".parse().unwrap());
+
+    let merchant = Merchant::retrieve(&client, &merchant_token).await.unwrap();
+
+    assert_eq!(merchant.token, "mrch_roZOG7c8IY4_8ak2gS8Yrw");
+}
@@ -0,0 +1,176 @@
+use pinpayments::{
+    ApplePayHeader, ApplePayPaymentData, CardScheme, Charge, Client, CreateCharge,
+    CreatePaymentSource, Currency, Customer, PaymentSource, PaymentSourceType
+};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use http::StatusCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn create_apple_pay_payment_source_test() {
+    let json = common::get_fixture("tests/fixtures/create-payment-source.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/payment_sources"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("type", "applepay")))),
+                request::body(url_decoded(contains(("source[data]", "encrypted-payment-data")))),
+                request::body(url_decoded(contains(("source[header][transactionId]", "c1d2e3")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let payment_source = PaymentSource::create(
+        &client,
+        CreatePaymentSource::ApplePay(
+            ApplePayPaymentData {
+                data: "encrypted-payment-data",
+                signature: "signature",
+                version: "EC_v1",
+                header: ApplePayHeader {
+                    ephemeral_public_key: "ephemeral-public-key",
+                    public_key_hash: "public-key-hash",
+                    transaction_id: "c1d2e3"
+                }
+            }
+        )
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(payment_source.token, "ps_wLp5DuGPrXKXbfT7rDpUyg");
+    assert_eq!(payment_source.r#type, PaymentSourceType::ApplePay);
+    assert_eq!(payment_source.source.scheme.unwrap(), CardScheme::Visa);
+    assert_eq!(payment_source.source.display_number.unwrap(), "XXXX-XXXX-XXXX-1111");
+    assert!(payment_source.source.name.is_none());
+}
+
+#[tokio::test]
+async fn retrieve_payment_source_test() {
+    let json = common::get_fixture("tests/fixtures/get-payment-source.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let payment_source_token = "ps_wLp5DuGPrXKXbfT7rDpUyg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/payment_sources/{}", payment_source_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let payment_source = PaymentSource::retrieve(&client, &payment_source_token)
+        .await
+        .unwrap();
+
+    assert_eq!(payment_source.token, payment_source_token);
+    assert_eq!(payment_source.r#type, PaymentSourceType::Card);
+    assert_eq!(payment_source.source.expiry_month.unwrap(), 5);
+    assert_eq!(payment_source.source.name.unwrap(), "Roland Robot");
+}
+
+#[tokio::test]
+async fn create_charge_with_payment_source_test() {
+    let json = common::get_fixture("tests/fixtures/create-charge.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/charges"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("payment_source_token", "ps_wLp5DuGPrXKXbfT7rDpUyg")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let charge = Charge::create(
+        &client,
+        CreateCharge {
+            amount: 400,
+            currency: Some(Currency::AUD),
+            description: "test charge",
+            email: String::from("roland@pinpayments.com"),
+            ip_address: String::from("203.192.1.172"),
+            payment_source_token: Some("ps_wLp5DuGPrXKXbfT7rDpUyg".parse().unwrap()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg");
+}
+
+#[tokio::test]
+async fn create_customer_card_from_payment_source_test() {
+    let json = common::get_fixture("tests/fixtures/create-customer-card.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let customer_token = "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", format!("/1/customers/{}/cards", customer_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("payment_source_token", "ps_wLp5DuGPrXKXbfT7rDpUyg")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let card = Customer::create_card_from_payment_source(
+        &client,
+        &customer_token,
+        &"ps_wLp5DuGPrXKXbfT7rDpUyg".parse().unwrap()
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(card.token, "card_ZFThCjFi7wCNkopytxQVKA");
+    assert_eq!(card.customer_token.unwrap(), "cus_XZg1ULpWaROQCOT5PdwLkQ");
+}
@@ -1,4 +1,4 @@
-use pinpayments::{Client, Currency, CreatePlan, Plan, IntervalUnit, CustomerPermission};
+use pinpayments::{Client, Currency, CreatePlan, Plan, IntervalUnit, CustomerPermission, UpdatePlan};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -44,7 +44,6 @@ async fn create_plan_test() {
             trial_interval: Some(7),
             trial_interval_unit: Some(IntervalUnit::Day),
             customer_permissions: Some(vec![CustomerPermission::Cancel]),
-            ..Default::default()
          }
     )
     .await
@@ -52,7 +51,7 @@ async fn create_plan_test() {
 
     assert_eq!(plan.token, "plan_ZyDee4HNeUHFHC4SpM2idg");
     assert_eq!(plan.name, "Coffee Plan");
-    assert_eq!(plan.currency, Currency::USD);
+    assert_eq!(plan.money.currency, Currency::USD);
     assert_eq!(plan.setup_amount, 0);
     assert_eq!(plan.trial_amount, 0);
     assert_eq!(plan.interval, 30);
@@ -95,7 +94,7 @@ async fn list_plans_test() {
 
     assert_eq!(plans.items[0].token, "plan_ZyDee4HNeUHFHC4SpM2idg");
     assert_eq!(plans.items[0].name, "Coffee Plan");
-    assert_eq!(plans.items[0].amount, 1000);
+    assert_eq!(plans.items[0].money.amount, 1000);
     assert_eq!(plans.items[0].setup_amount, 0);
     assert_eq!(plans.items[0].trial_amount, 0);
     assert_eq!(plans.items[0].interval, 30);
@@ -132,7 +131,7 @@ async fn retrieve_plan_test() {
 
     assert_eq!(plan.token, "plan_ZyDee4HNeUHFHC4SpM2idg");
     assert_eq!(plan.name, "Coffee Plan");
-    assert_eq!(plan.amount, 1000);
+    assert_eq!(plan.money.amount, 1000);
     assert_eq!(plan.setup_amount, 0);
     assert_eq!(plan.trial_amount, 0);
     assert_eq!(plan.interval, 30);
@@ -142,6 +141,47 @@ async fn retrieve_plan_test() {
     assert_eq!(plan.created_at.unwrap(), datetime!(2023-12-28 5:44:36 UTC));
 }
 
+#[tokio::test]
+async fn update_plan_test() {
+    let json = common::get_fixture("tests/fixtures/update-plan.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let plan_token = "plan_ZyDee4HNeUHFHC4SpM2idg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/plans/{}", plan_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("name", "Espresso Plan")))),
+                request::body(url_decoded(contains(("customer_permissions[0]", "cancel")))),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let plan = Plan::update(
+        &client,
+        &plan_token,
+        UpdatePlan {
+            name: Some("Espresso Plan"),
+            customer_permissions: Some(vec![CustomerPermission::Cancel])
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(plan.token, "plan_ZyDee4HNeUHFHC4SpM2idg");
+    assert_eq!(plan.name, "Espresso Plan");
+    assert_eq!(plan.customer_permissions, vec![CustomerPermission::Cancel]);
+}
+
 #[tokio::test]
 async fn delete_plan_test() {
     let auth = BasicAuth::new("sk_test_12345", "");
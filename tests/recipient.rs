@@ -1,4 +1,4 @@
-use pinpayments::{Client, CreateRecipient, Recipient, CreateBankAccount};
+use pinpayments::{Client, CreateRecipient, Recipient, CreateBankAccount, UpdateRecipient, Currency};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -117,3 +117,79 @@ async fn get_recipient_test() {
     assert_eq!(recipient.bank_account.bank_name.unwrap(), "");
     assert_eq!(recipient.bank_account.branch.unwrap(), "");
 }
+
+#[tokio::test]
+async fn update_recipient_test() {
+    let json = common::get_fixture("tests/fixtures/update-recipient.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let recipient_token = "rp_a98a4fafROQCOT5PdwLkQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/recipients/{}", recipient_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("email", "roland.robot@pinpayments.com")))),
+                request::body(url_decoded(contains(("bank_account[bsb]", "654321")))),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+    let recipient = Recipient::update(
+        &client,
+        &recipient_token,
+        UpdateRecipient {
+            email: Some("roland.robot@pinpayments.com"),
+            bank_account: Some(CreateBankAccount {
+                name: "Mr Roland Robot",
+                bsb: "654321",
+                number: "987654789"
+            }),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(recipient.token, "rp_a98a4fafROQCOT5PdwLkQ");
+    assert_eq!(recipient.email, "roland.robot@pinpayments.com");
+    assert_eq!(recipient.bank_account.token, "ba_Q8SUr1CgSPnwRTmUntTAsA");
+    assert_eq!(recipient.bank_account.bsb, "654321");
+}
+
+#[tokio::test]
+async fn list_recipient_transfers_test() {
+    let json = common::get_fixture("tests/fixtures/get-transfers.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let recipient_token = "rp_a98a4fafROQCOT5PdwLkQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/recipients/{}/transfers", recipient_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+    let transfers = Recipient::list_transfers(&client, &recipient_token, None, None).await.unwrap();
+
+    assert_eq!(transfers.items[0].token, "tfer_lfUYEBK14zotCTykezJkfg");
+    assert_eq!(transfers.items[0].money.currency, Currency::AUD);
+    assert_eq!(transfers.items[0].money.amount, 400);
+    assert_eq!(transfers.items[0].recipient, "rp_a98a4fafROQCOT5PdwLkQ");
+}
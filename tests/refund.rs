@@ -60,8 +60,8 @@ async fn refund_list_test() {
 
     assert_eq!(refunds.items[0].token, "rf_ERCQy--Ay6o-NKGiUVcKKA");
     assert_eq!(refunds.items[0].success, None);
-    assert_eq!(refunds.items[0].amount, 400);
-    assert_eq!(refunds.items[0].currency, Currency::USD);
+    assert_eq!(refunds.items[0].money.amount, 400);
+    assert_eq!(refunds.items[0].money.currency, Currency::USD);
     assert_eq!(refunds.items[0].charge, "ch_bZ3RhJnIUZ8HhfvH8CCvfA");
     assert_eq!(refunds.items[0].created_at.unwrap(), datetime!(2012-10-27 13:00 UTC));
     assert_eq!(refunds.items[0].error_message, None);
@@ -86,8 +86,8 @@ async fn charge_refunds_test() {
 
     assert_eq!(refunds.items[0].token, "rf_ERCQy--Ay6o-NKGiUVcKKA");
     assert_eq!(refunds.items[0].success, None);
-    assert_eq!(refunds.items[0].amount, 400);
-    assert_eq!(refunds.items[0].currency, Currency::USD);
+    assert_eq!(refunds.items[0].money.amount, 400);
+    assert_eq!(refunds.items[0].money.currency, Currency::USD);
     assert_eq!(refunds.items[0].charge, "ch_bZ3RhJnIUZ8HhfvH8CCvfA");
     assert_eq!(refunds.items[0].created_at.unwrap(), datetime!(2012-10-27 13:00 UTC));
     assert_eq!(refunds.items[0].error_message, None);
@@ -112,8 +112,8 @@ async fn get_refund_test() {
 
     assert_eq!(refund.token, "rf_ERCQy--Ay6o-NKGiUVcKKA");
     assert_eq!(refund.success, None);
-    assert_eq!(refund.amount, 400);
-    assert_eq!(refund.currency, Currency::USD);
+    assert_eq!(refund.money.amount, 400);
+    assert_eq!(refund.money.currency, Currency::USD);
     assert_eq!(refund.charge, "ch_bZ3RhJnIUZ8HhfvH8CCvfA");
     assert_eq!(refund.created_at.unwrap(), datetime!(2012-10-27 13:00 UTC));
     assert_eq!(refund.error_message, None);
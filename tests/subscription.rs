@@ -1,4 +1,5 @@
-use pinpayments::{Client, Currency, CreateSubscription, Subscription};
+use pinpayments::{Client, Currency, CreateSubscription, LedgerEntry, LedgerEntryKind, LedgerEntryType, Subscription, UpdateSubscription, SubscriptionState};
+use futures::stream::TryStreamExt;
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
@@ -42,7 +43,7 @@ async fn create_subscription_test() {
     .unwrap();
 
     assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
-    assert_eq!(subscription.state, "active");
+    assert_eq!(subscription.state, SubscriptionState::Active);
     assert_eq!(subscription.next_billing_date.unwrap(), datetime!(2023-12-28 22:05:8 UTC));
     assert_eq!(subscription.active_interval_started_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscription.active_interval_finishes_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
@@ -80,7 +81,7 @@ async fn list_subscription_test() {
     let subscriptions = Subscription::list(&client, None, None).await.unwrap();
 
     assert_eq!(subscriptions.items[0].token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
-    assert_eq!(subscriptions.items[0].state, "active");
+    assert_eq!(subscriptions.items[0].state, SubscriptionState::Active);
     assert_eq!(subscriptions.items[0].next_billing_date.unwrap(), datetime!(2023-12-28 22:05:8 UTC));
     assert_eq!(subscriptions.items[0].active_interval_started_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscriptions.items[0].active_interval_finishes_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
@@ -120,7 +121,7 @@ async fn retrieve_subscription_test() {
     let subscription = Subscription::retrieve(&client, &subscription_token).await.unwrap();
 
     assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
-    assert_eq!(subscription.state, "active");
+    assert_eq!(subscription.state, SubscriptionState::Active);
     assert_eq!(subscription.next_billing_date.unwrap(), datetime!(2023-12-28 22:05:8 UTC));
     assert_eq!(subscription.active_interval_started_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscription.active_interval_finishes_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
@@ -160,7 +161,7 @@ async fn delete_subscription_test() {
     let subscription = Subscription::delete(&client, &subscription_token).await.unwrap();
 
     assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
-    assert_eq!(subscription.state, "cancelled");
+    assert_eq!(subscription.state, SubscriptionState::Cancelled);
     assert_eq!(subscription.next_billing_date, None);
     assert_eq!(subscription.active_interval_started_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscription.active_interval_finishes_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
@@ -171,6 +172,115 @@ async fn delete_subscription_test() {
     assert_eq!(subscription.card_token, "card_nytGw7koRg23EEp9NTmz9w");
 }
 
+#[tokio::test]
+async fn create_subscription_with_card_test() {
+    let json = common::get_fixture("tests/fixtures/create-subscription.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/subscriptions"),
+                request::body(url_decoded(contains(("card_token", "card_nytGw7koRg23EEp9NTmz9w")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let subscription = Subscription::create(
+        &client,
+        CreateSubscription {
+            plan_token: "plan_ZyDee4HNeUHFHC4SpM2idg".parse().unwrap(),
+            customer_token: "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap(),
+            card_token: Some("card_nytGw7koRg23EEp9NTmz9w".parse().unwrap()),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(subscription.card_token, "card_nytGw7koRg23EEp9NTmz9w");
+}
+
+#[tokio::test]
+async fn create_subscription_with_payment_source_test() {
+    let json = common::get_fixture("tests/fixtures/create-subscription.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/subscriptions"),
+                request::body(url_decoded(contains(("payment_source_token", "ps_wLp5DuGPrXKXbfT7rDpUyg")))),
+                request::body(url_decoded(not(contains(key("card_token"))))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let subscription = Subscription::create(
+        &client,
+        CreateSubscription {
+            plan_token: "plan_ZyDee4HNeUHFHC4SpM2idg".parse().unwrap(),
+            customer_token: "cus_XZg1ULpWaROQCOT5PdwLkQ".parse().unwrap(),
+            payment_source_token: Some("ps_wLp5DuGPrXKXbfT7rDpUyg".parse().unwrap()),
+            ..Default::default()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
+}
+
+#[tokio::test]
+async fn update_subscription_test() {
+    let json = common::get_fixture("tests/fixtures/update-subscription.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let subscription_token = "sub_bZWXhTzHooKpk9FZjQfzqQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/subscriptions/{}", subscription_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("card_token", "card_ZFThCjFi7wCNkopytxQVKA")))),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let subscription = Subscription::update(
+        &client,
+        &subscription_token,
+        UpdateSubscription {
+            card_token: "card_ZFThCjFi7wCNkopytxQVKA".parse().unwrap()
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
+    assert_eq!(subscription.card_token, "card_ZFThCjFi7wCNkopytxQVKA");
+}
+
 #[tokio::test]
 async fn reactivate_subscription_test() {
     let json = common::get_fixture("tests/fixtures/reactivate-subscription.json");
@@ -200,7 +310,7 @@ async fn reactivate_subscription_test() {
     let subscription = Subscription::reactivate(&client, &subscription_token).await.unwrap();
 
     assert_eq!(subscription.token, "sub_bZWXhTzHooKpk9FZjQfzqQ");
-    assert_eq!(subscription.state, "active");
+    assert_eq!(subscription.state, SubscriptionState::Active);
     assert_eq!(subscription.next_billing_date.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscription.active_interval_started_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
     assert_eq!(subscription.active_interval_finishes_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
@@ -242,12 +352,59 @@ async fn list_subscription_ledger_entries_test() {
 
 
     assert_eq!(ledger_entries.items[0].created_at.unwrap(), datetime!(2023-12-28 22:05:08 UTC));
-    assert_eq!(ledger_entries.items[0].r#type, "credit");
-    assert_eq!(ledger_entries.items[0].amount, 1000);
-    assert_eq!(ledger_entries.items[0].currency, Currency::AUD);
-    assert_eq!(ledger_entries.items[0].annotation, "charge_credit");
+    assert_eq!(ledger_entries.items[0].r#type, LedgerEntryType::Credit);
+    assert_eq!(ledger_entries.items[0].money.amount, 1000);
+    assert_eq!(ledger_entries.items[0].money.currency, Currency::AUD);
+    assert_eq!(ledger_entries.items[0].annotation, LedgerEntryKind::ChargeCredit);
 
     assert_eq!(ledger_entries.pagination.count, 1);
     assert_eq!(ledger_entries.pagination.per_page, 25);
     assert_eq!(ledger_entries.pagination.current, 1);
 }
+
+#[tokio::test]
+async fn subscription_ledger_entries_paginator_test() {
+    let json = common::get_fixture("tests/fixtures/get-subscription-ledger-statement.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let subscription_token = "sub_bZWXhTzHooKpk9FZjQfzqQ".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/subscriptions/{}/ledger", subscription_token)),
+                request::query(url_decoded(contains(("per_page", "10")))),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let entries: Vec<_> = Subscription::list_ledger_entries_with_paginator(&client, &subscription_token, Some(10))
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[1].r#type, LedgerEntryType::Debit);
+    assert_eq!(entries[2].r#type, LedgerEntryType::Other(String::from("adjustment")));
+    assert_eq!(entries[2].signed_amount(), None);
+    assert_eq!(entries[0].annotation, LedgerEntryKind::ChargeCredit);
+    assert_eq!(entries[1].annotation, LedgerEntryKind::IntervalAmount);
+    assert_eq!(entries[2].annotation, LedgerEntryKind::Other(String::from("manual_adjustment")));
+    assert_eq!(entries[4].annotation, LedgerEntryKind::SetupAmount);
+
+    assert_eq!(LedgerEntry::balance(&entries), None);
+    assert!(LedgerEntry::running_balances(&entries).is_none());
+
+    let known: Vec<&LedgerEntry> = entries.iter().filter(|entry| entry.signed_amount().is_some()).collect();
+    assert_eq!(LedgerEntry::balance(known.iter().copied()), Some(0));
+
+    let balances: Vec<i64> = LedgerEntry::running_balances(known.iter().copied().rev())
+        .unwrap()
+        .into_iter()
+        .map(|(_, balance)| balance)
+        .collect();
+    assert_eq!(balances, vec![-1500, 0, -1000, 0]);
+}
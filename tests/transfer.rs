@@ -1,7 +1,8 @@
-use pinpayments::{Client, Currency, CreateTransfer, Transfer, TransferSearchParams};
+use pinpayments::{Client, Currency, CreateTransfer, Transfer, TransferSearchParams, TransferStatus};
 use httptest::{Expectation, matchers::*, responders::*};
 use surf::http::auth::BasicAuth;
 use time::macros::datetime;
+use futures::stream::TryStreamExt;
 
 pub mod common;
 
@@ -42,10 +43,10 @@ async fn create_transfer_test() {
         .unwrap();
 
     assert_eq!(transfer.token, "tfer_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(transfer.status, "succeeded");
-    assert_eq!(transfer.currency, Currency::AUD);
+    assert_eq!(transfer.status, TransferStatus::Succeeded);
+    assert_eq!(transfer.money.currency, Currency::AUD);
     assert_eq!(transfer.description, "Earnings for may");
-    assert_eq!(transfer.amount, 400);
+    assert_eq!(transfer.money.amount, 400);
     assert_eq!(transfer.total_debits, 200);
     assert_eq!(transfer.total_credits, 600);
     assert_eq!(transfer.created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
@@ -88,10 +89,10 @@ async fn list_transfers_test() {
     let transfers = Transfer::list(&client, None, None).await.unwrap();
 
     assert_eq!(transfers.items[0].token, "tfer_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(transfers.items[0].status, "succeeded");
-    assert_eq!(transfers.items[0].currency, Currency::AUD);
+    assert_eq!(transfers.items[0].status, TransferStatus::Succeeded);
+    assert_eq!(transfers.items[0].money.currency, Currency::AUD);
     assert_eq!(transfers.items[0].description, "Earnings for may");
-    assert_eq!(transfers.items[0].amount, 400);
+    assert_eq!(transfers.items[0].money.amount, 400);
     assert_eq!(transfers.items[0].total_debits, 200);
     assert_eq!(transfers.items[0].total_credits, 600);
     assert_eq!(transfers.items[0].created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
@@ -141,10 +142,10 @@ async fn search_transfer_test() {
         .unwrap();
 
     assert_eq!(transfers.items[0].token, "tfer_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(transfers.items[0].status, "succeeded");
-    assert_eq!(transfers.items[0].currency, Currency::AUD);
+    assert_eq!(transfers.items[0].status, TransferStatus::Succeeded);
+    assert_eq!(transfers.items[0].money.currency, Currency::AUD);
     assert_eq!(transfers.items[0].description, "Earnings for may");
-    assert_eq!(transfers.items[0].amount, 400);
+    assert_eq!(transfers.items[0].money.amount, 400);
     assert_eq!(transfers.items[0].total_debits, 200);
     assert_eq!(transfers.items[0].total_credits, 600);
     assert_eq!(transfers.items[0].created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
@@ -188,10 +189,10 @@ async fn retrieve_transfer_test() {
     let transfer = Transfer::retrieve(&client, &transfer_token).await.unwrap();
 
     assert_eq!(transfer.token, "tfer_lfUYEBK14zotCTykezJkfg");
-    assert_eq!(transfer.status, "succeeded");
-    assert_eq!(transfer.currency, Currency::AUD);
+    assert_eq!(transfer.status, TransferStatus::Succeeded);
+    assert_eq!(transfer.money.currency, Currency::AUD);
     assert_eq!(transfer.description, "Earnings for may");
-    assert_eq!(transfer.amount, 400);
+    assert_eq!(transfer.money.amount, 400);
     assert_eq!(transfer.total_debits, 200);
     assert_eq!(transfer.total_credits, 600);
     assert_eq!(transfer.created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
@@ -206,3 +207,68 @@ async fn retrieve_transfer_test() {
     assert_eq!(transfer.bank_account.branch.as_ref().unwrap(), "");
     assert_eq!(transfer.recipient, "rp_a98a4fafROQCOT5PdwLkQ");
 }
+
+#[tokio::test]
+async fn transfer_line_items_test() {
+    let json = common::get_fixture("tests/fixtures/get-transfer-line-items.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let transfer_token = "tfer_lfUYEBK14zotCTykezJkfg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/transfers/{}/line_items", transfer_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let line_items = Transfer::line_items(&client, &transfer_token, None, None).await.unwrap();
+
+    assert_eq!(line_items.items.len(), 3);
+    assert_eq!(line_items.items[0].r#type, "charge");
+    assert_eq!(line_items.items[0].money.amount, 600);
+    assert_eq!(line_items.items[0].money.currency, Currency::AUD);
+    assert_eq!(line_items.items[0].object, "charge");
+    assert_eq!(line_items.items[0].token, "ch_lfUYEBK14zotCTykezJkfg");
+    assert_eq!(line_items.items[0].created_at.unwrap(), datetime!(2012-06-20 3:10:49 UTC));
+    assert_eq!(line_items.items[1].r#type, "refund");
+    assert_eq!(line_items.items[1].money.amount, -100);
+    assert_eq!(line_items.items.iter().map(|item| item.money.amount).sum::<i64>(), 400);
+}
+
+#[tokio::test]
+async fn transfer_line_items_paginator_test() {
+    let json = common::get_fixture("tests/fixtures/get-transfer-line-items.json");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let transfer_token = "tfer_lfUYEBK14zotCTykezJkfg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/transfers/{}/line_items", transfer_token)),
+                request::query(url_decoded(contains(("per_page", "10")))),
+            ]).
+            respond_with(json_encoded(json)),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let line_items: Vec<_> = Transfer::line_items_with_paginator(&client, &transfer_token, Some(10))
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(line_items.len(), 3);
+    assert_eq!(line_items[2].r#type, "charge_fee");
+}
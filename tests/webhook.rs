@@ -0,0 +1,106 @@
+use pinpayments::{Client, Webhook};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+
+pub mod common;
+
+#[tokio::test]
+async fn list_webhooks_test() {
+    let json = common::get_fixture("tests/fixtures/get-webhooks.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/webhooks"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhooks = Webhook::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(webhooks.items.len(), 2);
+    assert_eq!(webhooks.items[0].token, "whr_3ttMJbAFjwtbeNBVE5AANg");
+    assert_eq!(webhooks.items[0].event, "evt_YZvAv1AjJ6N6FjPnm8LdbQ");
+    assert_eq!(webhooks.items[0].status, "failed");
+    assert_eq!(webhooks.items[0].number_of_attempts, 3);
+    assert_eq!(webhooks.items[0].response_code, Some(502));
+    assert!(webhooks.items[0].accepted_at.is_none());
+    assert_eq!(webhooks.items[1].status, "success");
+    assert_eq!(webhooks.items[1].accepted_at.unwrap(), datetime!(2023-12-29 2:41:52 UTC));
+}
+
+#[tokio::test]
+async fn retrieve_webhook_test() {
+    let json = common::get_fixture("tests/fixtures/get-webhook.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let webhook_token = "whr_3ttMJbAFjwtbeNBVE5AANg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/webhooks/{}", webhook_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhook = Webhook::retrieve(&client, &webhook_token).await.unwrap();
+
+    assert_eq!(webhook.token, "whr_3ttMJbAFjwtbeNBVE5AANg");
+    assert_eq!(webhook.url, "https://example.org/webhooks/");
+    assert_eq!(webhook.webhook_endpoint.unwrap(), "whe_5LDFpzkkn6BZ3UPr9wLeTg");
+    assert_eq!(webhook.error.unwrap(), "Bad Gateway");
+    assert_eq!(webhook.payload.unwrap()["type"], "charge.succeeded");
+    assert_eq!(webhook.last_attempted_at.unwrap(), datetime!(2023-12-29 3:11:12 UTC));
+    assert_eq!(webhook.created_at.unwrap(), datetime!(2023-12-29 2:58:40 UTC));
+}
+
+#[tokio::test]
+async fn replay_webhook_test() {
+    let json = common::get_fixture("tests/fixtures/replay-webhook.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let webhook_token = "whr_3ttMJbAFjwtbeNBVE5AANg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("PUT", format!("/1/webhooks/{}/replay", webhook_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhook = Webhook::replay(&client, &webhook_token).await.unwrap();
+
+    assert_eq!(webhook.token, "whr_3ttMJbAFjwtbeNBVE5AANg");
+    assert_eq!(webhook.status, "pending");
+    assert_eq!(webhook.next_attempt_at.unwrap(), datetime!(2023-12-29 4:02:00 UTC));
+}
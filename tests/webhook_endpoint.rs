@@ -0,0 +1,138 @@
+use pinpayments::{Client, CreateWebhookEndpoint, WebhookEndpoint};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+use time::macros::datetime;
+use http::StatusCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn create_webhook_endpoint_test() {
+    let json = common::get_fixture("tests/fixtures/create-webhook-endpoint.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("POST", "/1/webhook_endpoints"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+                request::body(url_decoded(contains(("url", "https://example.org/webhooks/")))),
+            ]).
+            respond_with(
+                status_code(StatusCode::CREATED.into())
+                .append_header("Content-Type", "application/json")
+                .body(serde_json::to_string(&json).expect("failed to serialize body"))),
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhook_endpoint = WebhookEndpoint::create(
+        &client,
+        CreateWebhookEndpoint {
+            url: "https://example.org/webhooks/"
+        }
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(webhook_endpoint.token, "whe_5LDFpzkkn6BZ3UPr9wLeTg");
+    assert_eq!(webhook_endpoint.url, "https://example.org/webhooks/");
+    assert_eq!(webhook_endpoint.created_at.unwrap(), datetime!(2023-12-29 2:41:37 UTC));
+    assert_eq!(webhook_endpoint.updated_at.unwrap(), datetime!(2023-12-29 2:41:37 UTC));
+}
+
+#[tokio::test]
+async fn list_webhook_endpoints_test() {
+    let json = common::get_fixture("tests/fixtures/get-webhook-endpoints.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/webhook_endpoints"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhook_endpoints = WebhookEndpoint::list(&client, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(webhook_endpoints.items.len(), 2);
+    assert_eq!(webhook_endpoints.items[0].token, "whe_5LDFpzkkn6BZ3UPr9wLeTg");
+    assert_eq!(webhook_endpoints.items[0].url, "https://example.org/webhooks/");
+    assert_eq!(webhook_endpoints.items[1].token, "whe_KxgXVaQvYQvTgVfRwL2ctQ");
+    assert_eq!(webhook_endpoints.items[1].created_at.unwrap(), datetime!(2023-12-28 23:12:05 UTC));
+    assert_eq!(webhook_endpoints.pagination.count, 2);
+}
+
+#[tokio::test]
+async fn retrieve_webhook_endpoint_test() {
+    let json = common::get_fixture("tests/fixtures/get-webhook-endpoint.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let webhook_endpoint_token = "whe_5LDFpzkkn6BZ3UPr9wLeTg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", format!("/1/webhook_endpoints/{}", webhook_endpoint_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let webhook_endpoint = WebhookEndpoint::retrieve(&client, &webhook_endpoint_token).await.unwrap();
+
+    assert_eq!(webhook_endpoint.token, "whe_5LDFpzkkn6BZ3UPr9wLeTg");
+    assert_eq!(webhook_endpoint.url, "https://example.org/webhooks/");
+    assert_eq!(webhook_endpoint.created_at.unwrap(), datetime!(2023-12-29 2:41:37 UTC));
+}
+
+#[tokio::test]
+async fn delete_webhook_endpoint_test() {
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    let webhook_endpoint_token = "whe_5LDFpzkkn6BZ3UPr9wLeTg".parse().unwrap();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("DELETE", format!("/1/webhook_endpoints/{}", webhook_endpoint_token)),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(status_code(StatusCode::NO_CONTENT.into()))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let result = WebhookEndpoint::delete(&client, &webhook_endpoint_token)
+        .await
+        .unwrap();
+
+    assert_eq!(result, StatusCode::NO_CONTENT);
+}
@@ -0,0 +1,94 @@
+use pinpayments::{Client, EventData, PinError, WebhookPayload};
+use httptest::{Expectation, matchers::*, responders::*};
+use surf::http::auth::BasicAuth;
+
+pub mod common;
+
+fn webhook_body() -> Vec<u8> {
+    std::fs::read("tests/fixtures/webhook-charge-succeeded.json").expect("file should be readable")
+}
+
+#[test]
+fn parse_webhook_test() {
+    let body = webhook_body();
+
+    let event = WebhookPayload::parse(&body, [("Content-Type", "application/json; charset=utf-8")]).unwrap();
+
+    assert_eq!(event.token, "evt_YZvAv1AjJ6N6FjPnm8LdbQ");
+    match event.data {
+        EventData::ChargeSucceeded(charge) => assert_eq!(charge.token, "ch_lfUYEBK14zotCTykezJkfg"),
+        other => panic!("Expected ChargeSucceeded {:?}", other),
+    }
+}
+
+#[test]
+fn parse_webhook_rejects_invalid_payloads_test() {
+    let body = webhook_body();
+    let no_headers: [(&str, &str); 0] = [];
+
+    match WebhookPayload::parse(&body, [("content-type", "text/plain")]) {
+        Err(PinError::InvalidWebhook(_)) => (),
+        other => panic!("Expected InvalidWebhook {:?}", other),
+    }
+
+    match WebhookPayload::parse(b"", no_headers) {
+        Err(PinError::InvalidWebhook(_)) => (),
+        other => panic!("Expected InvalidWebhook {:?}", other),
+    }
+
+    match WebhookPayload::parse(br#"{"token": "ch_lfUYEBK14zotCTykezJkfg", "type": "charge.succeeded"}"#, no_headers) {
+        Err(PinError::JSONSerialize(_)) => (),
+        other => panic!("Expected JSONSerialize {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn verify_webhook_test() {
+    let json = common::get_fixture("tests/fixtures/get-event.json");
+
+    let auth = BasicAuth::new("sk_test_12345", "");
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(
+            all_of![
+                request::method_path("GET", "/1/events/evt_YZvAv1AjJ6N6FjPnm8LdbQ"),
+                request::headers(
+                    contains((String::from(auth.name().as_str()), String::from(auth.value().as_str())))
+                ),
+            ]).
+            respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let event = WebhookPayload::verify(&client, &webhook_body(), [("Content-Type", "application/json")])
+        .await
+        .unwrap();
+
+    assert_eq!(event.token, "evt_YZvAv1AjJ6N6FjPnm8LdbQ");
+    assert_eq!(event.event_type(), "charge.succeeded");
+}
+
+#[tokio::test]
+async fn verify_webhook_mismatch_test() {
+    let mut json = common::get_fixture("tests/fixtures/get-event.json");
+    json["response"]["type"] = "charge.failed".into();
+
+    let server = common::SERVER_POOL.get_server();
+
+    server.expect(
+        Expectation::matching(request::method_path("GET", "/1/events/evt_YZvAv1AjJ6N6FjPnm8LdbQ"))
+            .respond_with(json_encoded(json))
+    );
+
+    let client = Client::from_url(server.url_str("/1/").as_str(), "sk_test_12345");
+
+    let result = WebhookPayload::verify(&client, &webhook_body(), [("Content-Type", "application/json")]).await;
+
+    match result {
+        Err(PinError::InvalidWebhook(_)) => (),
+        other => panic!("Expected InvalidWebhook {:?}", other),
+    }
+}